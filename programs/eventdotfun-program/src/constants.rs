@@ -2,3 +2,12 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+#[constant]
+pub const MAX_TICKETS_PER_BUY: u64 = 10;
+
+#[constant]
+pub const BASIS_POINTS: u64 = 10_000;
+
+#[constant]
+pub const MAX_FEE_BPS: u64 = 1_000;
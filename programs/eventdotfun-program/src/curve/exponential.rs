@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use super::{fixed_pow, ratio, PricingCurve, PRICE_PRECISION};
+use crate::error::ErrorCode;
+
+/// Each ticket is `growth_rate_bps` more expensive than the previous one,
+/// rescaled so the threshold ticket costs `last_price`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Exponential {
+    pub growth_rate_bps: u16,
+}
+
+impl Exponential {
+    fn growth(&self, x: u64) -> Result<u128> {
+        let base = PRICE_PRECISION + self.growth_rate_bps as u128 * PRICE_PRECISION / 10_000;
+
+        Ok(fixed_pow(base, x)? - PRICE_PRECISION)
+    }
+}
+
+impl PricingCurve for Exponential {
+    fn validate(&self, min_ticket_to_sold: u64) -> Result<()> {
+        require!(self.growth_rate_bps > 0, ErrorCode::InvalidCurveParameters);
+        self.progress(min_ticket_to_sold, min_ticket_to_sold)?;
+
+        Ok(())
+    }
+
+    fn progress(&self, x: u64, min_ticket_to_sold: u64) -> Result<u128> {
+        ratio(self.growth(x)?, self.growth(min_ticket_to_sold)?)
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+use super::{ratio, PricingCurve};
+
+/// Constant increase per ticket.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Linear {}
+
+impl PricingCurve for Linear {
+    fn validate(&self, _min_ticket_to_sold: u64) -> Result<()> {
+        Ok(())
+    }
+
+    fn progress(&self, x: u64, min_ticket_to_sold: u64) -> Result<u128> {
+        ratio(x as u128, min_ticket_to_sold as u128)
+    }
+}
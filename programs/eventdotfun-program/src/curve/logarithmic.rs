@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use super::{fixed_log2, ratio, PricingCurve, PRICE_PRECISION};
+use crate::error::ErrorCode;
+
+/// `log(1 + rate * x)`, cheap early tickets that rise quickly and then
+/// flatten out towards the threshold.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Logarithmic {
+    pub rate_bps: u16,
+}
+
+impl Logarithmic {
+    fn growth(&self, x: u64) -> Result<u128> {
+        let scaled = (self.rate_bps as u128)
+            .checked_mul(x as u128)
+            .and_then(|rate| rate.checked_mul(PRICE_PRECISION))
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?
+            / 10_000;
+
+        fixed_log2(PRICE_PRECISION + scaled)
+    }
+}
+
+impl PricingCurve for Logarithmic {
+    fn validate(&self, min_ticket_to_sold: u64) -> Result<()> {
+        require!(self.rate_bps > 0, ErrorCode::InvalidCurveParameters);
+        self.progress(min_ticket_to_sold, min_ticket_to_sold)?;
+
+        Ok(())
+    }
+
+    fn progress(&self, x: u64, min_ticket_to_sold: u64) -> Result<u128> {
+        ratio(self.growth(x)?, self.growth(min_ticket_to_sold)?)
+    }
+}
@@ -0,0 +1,324 @@
+pub mod exponential;
+pub mod linear;
+pub mod logarithmic;
+pub mod polynomial;
+pub mod sigmoid;
+
+pub use exponential::*;
+pub use linear::*;
+pub use logarithmic::*;
+pub use polynomial::*;
+pub use sigmoid::*;
+
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Fixed-point scale used by every curve shape.
+pub const PRICE_PRECISION: u128 = 1_000_000_000;
+
+/// Shape of the price ramp between `initial_price` and `last_price`.
+///
+/// Every shape reaches `last_price` at `min_ticket_to_sold` and stays flat
+/// afterwards, so the vault always covers the threshold tickets.
+pub trait PricingCurve {
+    fn validate(&self, min_ticket_to_sold: u64) -> Result<()>;
+
+    /// Share of the price spread reached at ticket `x`, scaled by
+    /// `PRICE_PRECISION`. Must be `0` at `x == 0`, `PRICE_PRECISION` at
+    /// `x == min_ticket_to_sold` and non-decreasing in between.
+    fn progress(&self, x: u64, min_ticket_to_sold: u64) -> Result<u128>;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurveKind {
+    Linear(Linear),
+    Polynomial(Polynomial),
+    Exponential(Exponential),
+    Logarithmic(Logarithmic),
+    Sigmoid(Sigmoid),
+}
+
+impl CurveKind {
+    pub const INIT_SPACE: usize = 1 + 8 + 8;
+}
+
+impl PricingCurve for CurveKind {
+    fn validate(&self, min_ticket_to_sold: u64) -> Result<()> {
+        match self {
+            CurveKind::Linear(curve) => curve.validate(min_ticket_to_sold),
+            CurveKind::Polynomial(curve) => curve.validate(min_ticket_to_sold),
+            CurveKind::Exponential(curve) => curve.validate(min_ticket_to_sold),
+            CurveKind::Logarithmic(curve) => curve.validate(min_ticket_to_sold),
+            CurveKind::Sigmoid(curve) => curve.validate(min_ticket_to_sold),
+        }
+    }
+
+    fn progress(&self, x: u64, min_ticket_to_sold: u64) -> Result<u128> {
+        match self {
+            CurveKind::Linear(curve) => curve.progress(x, min_ticket_to_sold),
+            CurveKind::Polynomial(curve) => curve.progress(x, min_ticket_to_sold),
+            CurveKind::Exponential(curve) => curve.progress(x, min_ticket_to_sold),
+            CurveKind::Logarithmic(curve) => curve.progress(x, min_ticket_to_sold),
+            CurveKind::Sigmoid(curve) => curve.progress(x, min_ticket_to_sold),
+        }
+    }
+}
+
+/// Price of the `ticket_number`-th ticket on `curve`.
+pub fn price_at(
+    curve: &impl PricingCurve,
+    initial_price: u64,
+    last_price: u64,
+    min_ticket_to_sold: u64,
+    ticket_number: u64,
+) -> Result<u64> {
+    let x = ticket_number.min(min_ticket_to_sold);
+    let spread = last_price
+        .checked_sub(initial_price)
+        .ok_or(error!(ErrorCode::InvalidPrice))? as u128;
+    let progress = curve.progress(x, min_ticket_to_sold)?.min(PRICE_PRECISION);
+
+    let increment = spread
+        .checked_mul(progress)
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))?
+        / PRICE_PRECISION;
+
+    (initial_price as u128)
+        .checked_add(increment)
+        .and_then(|price| u64::try_from(price).ok())
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))
+}
+
+pub fn checked_pow(base: u64, exponent: u8) -> Result<u128> {
+    (base as u128)
+        .checked_pow(exponent as u32)
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// `numerator / denominator` scaled by `PRICE_PRECISION`.
+pub fn ratio(numerator: u128, denominator: u128) -> Result<u128> {
+    numerator
+        .checked_mul(PRICE_PRECISION)
+        .and_then(|scaled| scaled.checked_div(denominator))
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))
+}
+
+/// `base ^ exponent` where `base` is scaled by `PRICE_PRECISION`.
+pub fn fixed_pow(base: u128, mut exponent: u64) -> Result<u128> {
+    let mut result = PRICE_PRECISION;
+    let mut square = base;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = fixed_mul(result, square)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            square = fixed_mul(square, square)?;
+        }
+    }
+
+    Ok(result)
+}
+
+/// `log2(value)` for `value >= 1`, both scaled by `PRICE_PRECISION`.
+pub fn fixed_log2(value: u128) -> Result<u128> {
+    require!(value >= PRICE_PRECISION, ErrorCode::ArithmeticOverflow);
+
+    let mut integer_part = 0_u128;
+    let mut y = value;
+    while y >= 2 * PRICE_PRECISION {
+        y /= 2;
+        integer_part += 1;
+    }
+
+    let mut result = integer_part * PRICE_PRECISION;
+    let mut bit = PRICE_PRECISION / 2;
+    while bit > 0 {
+        y = fixed_mul(y, y)?;
+        if y >= 2 * PRICE_PRECISION {
+            y /= 2;
+            result += bit;
+        }
+        bit /= 2;
+    }
+
+    Ok(result)
+}
+
+fn fixed_mul(a: u128, b: u128) -> Result<u128> {
+    a.checked_mul(b)
+        .map(|product| product / PRICE_PRECISION)
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INITIAL_PRICE: u64 = 1_000_000;
+    const LAST_PRICE: u64 = 10_000_000;
+    const MIN_TICKET_TO_SOLD: u64 = 50;
+
+    fn shapes() -> Vec<CurveKind> {
+        vec![
+            CurveKind::Linear(Linear {}),
+            CurveKind::Polynomial(Polynomial { exponent: 1 }),
+            CurveKind::Polynomial(Polynomial { exponent: 2 }),
+            CurveKind::Polynomial(Polynomial {
+                exponent: Polynomial::MAX_EXPONENT,
+            }),
+            CurveKind::Exponential(Exponential { growth_rate_bps: 1 }),
+            CurveKind::Exponential(Exponential {
+                growth_rate_bps: 1_000,
+            }),
+            CurveKind::Logarithmic(Logarithmic { rate_bps: 1 }),
+            CurveKind::Logarithmic(Logarithmic { rate_bps: 10_000 }),
+            CurveKind::Sigmoid(Sigmoid {
+                midpoint: 0,
+                width: 1,
+            }),
+            CurveKind::Sigmoid(Sigmoid {
+                midpoint: MIN_TICKET_TO_SOLD / 2,
+                width: 5,
+            }),
+            CurveKind::Sigmoid(Sigmoid {
+                midpoint: MIN_TICKET_TO_SOLD,
+                width: 1_000,
+            }),
+        ]
+    }
+
+    fn price(curve: &CurveKind, ticket_number: u64) -> u64 {
+        price_at(
+            curve,
+            INITIAL_PRICE,
+            LAST_PRICE,
+            MIN_TICKET_TO_SOLD,
+            ticket_number,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn every_shape_validates() {
+        for curve in shapes() {
+            assert!(curve.validate(MIN_TICKET_TO_SOLD).is_ok(), "{curve:?}");
+        }
+    }
+
+    #[test]
+    fn starts_at_initial_price() {
+        for curve in shapes() {
+            assert_eq!(price(&curve, 0), INITIAL_PRICE, "{curve:?}");
+        }
+    }
+
+    #[test]
+    fn reaches_last_price_at_threshold() {
+        for curve in shapes() {
+            assert_eq!(price(&curve, MIN_TICKET_TO_SOLD), LAST_PRICE, "{curve:?}");
+        }
+    }
+
+    #[test]
+    fn prices_never_decrease() {
+        for curve in shapes() {
+            let mut previous = price(&curve, 0);
+            for ticket_number in 1..=MIN_TICKET_TO_SOLD {
+                let current = price(&curve, ticket_number);
+                assert!(current >= previous, "{curve:?} at {ticket_number}");
+                previous = current;
+            }
+        }
+    }
+
+    #[test]
+    fn flat_after_threshold() {
+        for curve in shapes() {
+            for ticket_number in [MIN_TICKET_TO_SOLD + 1, MIN_TICKET_TO_SOLD * 2, u64::MAX] {
+                assert_eq!(price(&curve, ticket_number), LAST_PRICE, "{curve:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_decreasing_prices() {
+        let curve = CurveKind::Linear(Linear {});
+        assert!(price_at(&curve, LAST_PRICE, INITIAL_PRICE, MIN_TICKET_TO_SOLD, 1).is_err());
+    }
+
+    #[test]
+    fn rejects_overflowing_curves() {
+        let overflowing = [
+            CurveKind::Polynomial(Polynomial {
+                exponent: Polynomial::MAX_EXPONENT,
+            }),
+            CurveKind::Exponential(Exponential {
+                growth_rate_bps: u16::MAX,
+            }),
+        ];
+        for curve in overflowing {
+            assert!(curve.validate(u64::MAX).is_err(), "{curve:?}");
+        }
+
+        let exponential = CurveKind::Exponential(Exponential {
+            growth_rate_bps: 1_000,
+        });
+        assert!(exponential.validate(1_000).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_parameters() {
+        let invalid = [
+            CurveKind::Polynomial(Polynomial { exponent: 0 }),
+            CurveKind::Polynomial(Polynomial {
+                exponent: Polynomial::MAX_EXPONENT + 1,
+            }),
+            CurveKind::Exponential(Exponential { growth_rate_bps: 0 }),
+            CurveKind::Logarithmic(Logarithmic { rate_bps: 0 }),
+            CurveKind::Sigmoid(Sigmoid {
+                midpoint: 0,
+                width: 0,
+            }),
+            CurveKind::Sigmoid(Sigmoid {
+                midpoint: MIN_TICKET_TO_SOLD + 1,
+                width: 1,
+            }),
+        ];
+        for curve in invalid {
+            assert!(curve.validate(MIN_TICKET_TO_SOLD).is_err(), "{curve:?}");
+        }
+    }
+
+    #[test]
+    fn fixed_pow_matches_integer_powers() {
+        assert_eq!(fixed_pow(2 * PRICE_PRECISION, 0).unwrap(), PRICE_PRECISION);
+        assert_eq!(
+            fixed_pow(2 * PRICE_PRECISION, 10).unwrap(),
+            1_024 * PRICE_PRECISION
+        );
+        assert_eq!(
+            fixed_pow(PRICE_PRECISION / 2, 3).unwrap(),
+            PRICE_PRECISION / 8
+        );
+        assert!(fixed_pow(2 * PRICE_PRECISION, 128).is_err());
+    }
+
+    #[test]
+    fn fixed_log2_matches_known_values() {
+        assert_eq!(fixed_log2(PRICE_PRECISION).unwrap(), 0);
+        assert_eq!(fixed_log2(2 * PRICE_PRECISION).unwrap(), PRICE_PRECISION);
+        assert_eq!(
+            fixed_log2(1_024 * PRICE_PRECISION).unwrap(),
+            10 * PRICE_PRECISION
+        );
+
+        // log2(3) = 1.584962500...
+        let log2_3 = fixed_log2(3 * PRICE_PRECISION).unwrap();
+        assert!(log2_3.abs_diff(1_584_962_500) < 100, "{log2_3}");
+
+        assert!(fixed_log2(PRICE_PRECISION - 1).is_err());
+    }
+}
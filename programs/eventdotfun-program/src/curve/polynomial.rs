@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use super::{checked_pow, ratio, PricingCurve};
+use crate::error::ErrorCode;
+
+/// `x ^ exponent`, steeper towards the threshold as the exponent grows.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    pub exponent: u8,
+}
+
+impl Polynomial {
+    pub const MAX_EXPONENT: u8 = 3;
+}
+
+impl PricingCurve for Polynomial {
+    fn validate(&self, min_ticket_to_sold: u64) -> Result<()> {
+        require!(
+            (1..=Self::MAX_EXPONENT).contains(&self.exponent),
+            ErrorCode::InvalidExponent
+        );
+        self.progress(min_ticket_to_sold, min_ticket_to_sold)?;
+
+        Ok(())
+    }
+
+    fn progress(&self, x: u64, min_ticket_to_sold: u64) -> Result<u128> {
+        ratio(
+            checked_pow(x, self.exponent)?,
+            checked_pow(min_ticket_to_sold, self.exponent)?,
+        )
+    }
+}
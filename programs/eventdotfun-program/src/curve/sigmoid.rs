@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use super::{PricingCurve, PRICE_PRECISION};
+use crate::error::ErrorCode;
+
+/// S-shaped ramp centred on `midpoint`. A smaller `width` gives a sharper
+/// jump around the midpoint.
+///
+/// Uses the algebraic sigmoid `t / (1 + |t|)` so no transcendental math is
+/// needed on-chain.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sigmoid {
+    pub midpoint: u64,
+    pub width: u64,
+}
+
+impl Sigmoid {
+    fn sigmoid(&self, x: u64) -> i128 {
+        let distance = x as i128 - self.midpoint as i128;
+
+        distance * PRICE_PRECISION as i128 / (self.width as i128 + distance.abs())
+    }
+}
+
+impl PricingCurve for Sigmoid {
+    fn validate(&self, min_ticket_to_sold: u64) -> Result<()> {
+        require!(
+            self.width > 0 && self.midpoint <= min_ticket_to_sold,
+            ErrorCode::InvalidCurveParameters
+        );
+        self.progress(min_ticket_to_sold, min_ticket_to_sold)?;
+
+        Ok(())
+    }
+
+    fn progress(&self, x: u64, min_ticket_to_sold: u64) -> Result<u128> {
+        let floor = self.sigmoid(0);
+        let range = self.sigmoid(min_ticket_to_sold) - floor;
+        require!(range > 0, ErrorCode::InvalidCurveParameters);
+
+        Ok(((self.sigmoid(x) - floor) * PRICE_PRECISION as i128 / range) as u128)
+    }
+}
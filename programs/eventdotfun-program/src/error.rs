@@ -5,7 +5,9 @@ pub enum ErrorCode {
     #[msg("Invalid Authority")]
     InvalidAuthority,
 
-    #[msg("Invalid Sales Type, should be 1 or 2")]
+    /// No longer returned: `SalesType` is now an enum checked on
+    /// deserialization. Kept so the codes after it don't shift.
+    #[msg("Deprecated")]
     InvalidSalesType,
 
     #[msg("Invalid Timestamp")]
@@ -40,4 +42,91 @@ pub enum ErrorCode {
 
     #[msg("Refund Not Opened")]
     RefundNotOpened,
+
+    #[msg("Invalid Fee, should not exceed MAX_FEE_BPS")]
+    InvalidFee,
+
+    #[msg("Invalid Fee Recipient")]
+    InvalidFeeRecipient,
+
+    #[msg("Invalid Referral Fee, should not exceed the protocol fee")]
+    InvalidReferralFee,
+
+    #[msg("Invalid Referrer")]
+    InvalidReferrer,
+
+    #[msg("Nothing To Claim")]
+    NothingToClaim,
+
+    #[msg("Invalid Refund Window")]
+    InvalidRefundWindow,
+
+    #[msg("Invalid Curve Parameters")]
+    InvalidCurveParameters,
+
+    #[msg("Invalid Event Metadata")]
+    InvalidMetadata,
+
+    #[msg("Invalid Event Info")]
+    InvalidEventInfo,
+
+    #[msg("Event Info Locked, Sale Already Started")]
+    EventInfoLocked,
+
+    #[msg("Invalid Vesting Schedule")]
+    InvalidVestingSchedule,
+
+    #[msg("Invalid Revenue Split")]
+    InvalidRevenueSplit,
+
+    #[msg("Invalid Split Recipient")]
+    InvalidSplitRecipient,
+
+    #[msg("Invalid Quantity")]
+    InvalidQuantity,
+
+    #[msg("Invalid Ticket Account")]
+    InvalidTicketAccount,
+
+    #[msg("Invalid Asset Owner")]
+    InvalidAssetOwner,
+
+    #[msg("Invalid Asset Collection")]
+    InvalidAssetCollection,
+
+    #[msg("Only The Last Ticket Can Be Sold")]
+    NotLastTicket,
+
+    #[msg("Price Exceeds Max Lamports")]
+    BuySlippageExceeded,
+
+    #[msg("Price Below Min Lamports")]
+    SellSlippageExceeded,
+
+    #[msg("Refund Closed")]
+    RefundClosed,
+
+    #[msg("Protocol Paused")]
+    ProtocolPaused,
+
+    #[msg("Curve Paused")]
+    CurvePaused,
+
+    #[msg("Event Cancelled")]
+    EventCancelled,
+
+    #[msg("Event Already Started")]
+    EventAlreadyStarted,
+
+    #[msg("Vault Insolvent")]
+    VaultInsolvent,
+
+    #[msg("Vesting In Progress")]
+    VestingInProgress,
+
+    #[msg("Obligations Outstanding")]
+    ObligationsOutstanding,
+
+    #[msg("Arithmetic Overflow")]
+    ArithmeticOverflow,
 }
@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+
+use crate::{Config, EventDetails, SalesType};
+
+#[event]
+pub struct CurveCreated {
+    pub bonding_curve: Pubkey,
+    pub collection: Pubkey,
+    pub creator: Pubkey,
+    pub sales_type: SalesType,
+    pub start_at: u64,
+    pub end_at: u64,
+    pub initial_price: u64,
+    pub last_price: u64,
+    pub min_ticket_to_sold: u64,
+    pub max_ticket_to_sold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketBought {
+    pub bonding_curve: Pubkey,
+    pub buyer: Pubkey,
+    pub asset: Pubkey,
+    pub ticket_number: u64,
+    pub price: u64,
+    pub current_ticket_sold: u64,
+    pub total_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketSold {
+    pub bonding_curve: Pubkey,
+    pub seller: Pubkey,
+    pub asset: Pubkey,
+    pub ticket_number: u64,
+    pub price: u64,
+    pub current_ticket_sold: u64,
+    pub total_sol: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Refunded {
+    pub bonding_curve: Pubkey,
+    pub user: Pubkey,
+    pub asset: Pubkey,
+    pub ticket_number: u64,
+    pub lamports: u64,
+    pub total_refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CurveClosed {
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+    pub lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EventCancelled {
+    pub bonding_curve: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CurvePauseUpdated {
+    pub bonding_curve: Pubkey,
+    pub authority: Pubkey,
+    pub paused: bool,
+    pub sales_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EventInfoUpdated {
+    pub bonding_curve: Pubkey,
+    pub details: EventDetails,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Withdrawn {
+    pub bonding_curve: Pubkey,
+    pub creator: Pubkey,
+    pub lamports: u64,
+    pub creator_lamports: u64,
+    pub total_withdrawn: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SplitClaimed {
+    pub bonding_curve: Pubkey,
+    pub recipient: Pubkey,
+    pub lamports: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+    pub fee: u64,
+    pub fee_recipient: Pubkey,
+    pub referral_fee: u64,
+    pub paused: bool,
+    pub timestamp: i64,
+}
+
+impl ConfigUpdated {
+    pub fn from_config(config: &Config) -> Result<Self> {
+        Ok(Self {
+            admin: config.admin,
+            pending_admin: config.pending_admin,
+            fee: config.fee,
+            fee_recipient: config.fee_recipient,
+            referral_fee: config.referral_fee,
+            paused: config.paused,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
+
+#[event]
+pub struct ReferralCreated {
+    pub referral: Pubkey,
+    pub referrer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReferralClaimed {
+    pub referral: Pubkey,
+    pub referrer: Pubkey,
+    pub lamports: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Config, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [Config::SEED.as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = config.pending_admin == Some(user.key()) @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+}
+
+impl<'info> AcceptAdmin<'info> {
+    pub fn accept_admin(&mut self) -> Result<ConfigUpdated> {
+        self.config.admin = self.user.key();
+        self.config.pending_admin = None;
+
+        ConfigUpdated::from_config(&self.config)
+    }
+}
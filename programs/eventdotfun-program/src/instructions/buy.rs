@@ -2,40 +2,59 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
-use mpl_core::{
-    accounts::{BaseAssetV1, BaseCollectionV1},
-    fetch_external_plugin_adapter, fetch_external_plugin_adapter_data_info,
-    instructions::{
-        BurnV1CpiBuilder, CreateV2CpiBuilder, WriteExternalPluginAdapterDataV1CpiBuilder,
-    },
-    types::{
-        AppDataInitInfo, Attribute, Attributes, ExternalPluginAdapter,
-        ExternalPluginAdapterInitInfo, ExternalPluginAdapterKey, ExternalPluginAdapterSchema,
-        Plugin, PluginAuthority, PluginAuthorityPair,
-    },
-    ID as MPL_CORE_ID,
-};
+use mpl_core::{accounts::BaseCollectionV1, ID as MPL_CORE_ID};
 
-use crate::{error::ErrorCode, BondingCurve};
+use super::shared::{pay_protocol_fee, TicketMint};
+use crate::{error::ErrorCode, BondingCurve, Config, Referral, TicketBought, TicketReceipt};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Buy<'info> {
     #[account(
         mut,
         seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
-        bump = bonding_curve.bump
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.paused && !bonding_curve.sales_paused @ ErrorCode::CurvePaused,
+        constraint = !bonding_curve.cancelled @ ErrorCode::EventCancelled
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
     #[account(mut, seeds = [b"vault", bonding_curve.key().as_ref()], bump = bonding_curve.vault_bump)]
     pub vault: SystemAccount<'info>,
 
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, address = config.fee_recipient @ ErrorCode::InvalidFeeRecipient)]
+    pub fee_recipient: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Referral::SEED.as_bytes(), referral.referrer.as_ref()],
+        bump = referral.bump,
+        constraint = referral.referrer != user.key() @ ErrorCode::InvalidReferrer
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+
     #[account(mut, constraint = collection.key() == bonding_curve.collection)]
     pub collection: Account<'info, BaseCollectionV1>,
 
     #[account(mut)]
     pub asset: Signer<'info>,
 
+    #[account(
+        init,
+        space = TicketReceipt::INIT_SPACE,
+        payer = user,
+        seeds = [TicketReceipt::SEED.as_bytes(), asset.key().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, TicketReceipt>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -47,37 +66,44 @@ pub struct Buy<'info> {
 }
 
 impl<'info> Buy<'info> {
-    pub fn buy(&mut self) -> Result<()> {
-        let ticket_index = self.bonding_curve.current_ticket_sold + 1;
-        let min_ticket_to_sold = self.bonding_curve.min_ticket_to_sold;
+    pub fn buy(&mut self, max_lamports: u64, bumps: &BuyBumps) -> Result<TicketBought> {
+        // tickets are priced by supply but numbered by mint order, so
+        // numbers stay unique after sell-backs
+        let curve_index = self
+            .bonding_curve
+            .current_ticket_sold
+            .checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        let ticket_number = self
+            .bonding_curve
+            .total_minted
+            .checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
         let max_ticket_to_sold = self.bonding_curve.max_ticket_to_sold;
-        let current_ticket_sold = self.bonding_curve.current_ticket_sold;
 
         let now = Clock::get()?.unix_timestamp as u64;
         let start_at = self.bonding_curve.start_at;
         let end_at = self.bonding_curve.end_at;
 
-        let initial_price = self.bonding_curve.initial_price;
-        let exponent = self.bonding_curve.exponent;
-        let multiplier = self.bonding_curve.multiplier;
-
         require!(now >= start_at, ErrorCode::CurveNotStarted);
         require!(now <= end_at, ErrorCode::CurveEnded);
         require!(
-            ticket_index <= max_ticket_to_sold,
+            curve_index <= max_ticket_to_sold,
             ErrorCode::MaxTicketReached
         );
 
-        // 1. calculate lamports
-        let lamports = if current_ticket_sold > min_ticket_to_sold {
-            initial_price + (min_ticket_to_sold ^ exponent as u64) * multiplier
-        } else {
-            initial_price + (ticket_index ^ exponent as u64) * multiplier
-        };
+        // 1. calculate lamports, bounding what the buyer pays including the fee
+        let lamports = self.bonding_curve.price_at(curve_index)?;
+        let total_fee = self.config.fee_for(lamports)?;
+        require!(
+            lamports
+                .checked_add(total_fee)
+                .is_some_and(|total| total <= max_lamports),
+            ErrorCode::BuySlippageExceeded
+        );
 
         // 2. update bonding curve state
-        self.bonding_curve.current_ticket_sold += 1_u64;
-        self.bonding_curve.total_sol += lamports;
+        self.bonding_curve.record_buy(lamports)?;
 
         // 3. transfer to vault
         let cpi_program = self.system_program.to_account_info();
@@ -88,40 +114,48 @@ impl<'info> Buy<'info> {
         let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_account);
         transfer(cpi_ctx, lamports)?;
 
-        // 4. create Core asset
-        let mut ticket_plugin: Vec<PluginAuthorityPair> = vec![];
-
-        let attribute_list: Vec<Attribute> = vec![Attribute {
-            key: "Ticket Number".to_string(),
-            value: ticket_index.to_string(),
-        }];
-
-        ticket_plugin.push(PluginAuthorityPair {
-            plugin: Plugin::Attributes(Attributes { attribute_list }),
-            authority: Some(PluginAuthority::UpdateAuthority),
+        // 4. transfer protocol fee, crediting the referrer's cut
+        pay_protocol_fee(
+            &self.config,
+            lamports,
+            &self.user.to_account_info(),
+            &self.fee_recipient.to_account_info(),
+            self.referral.as_mut(),
+            &cpi_program,
+        )?;
+
+        // 5. record what was paid
+        self.receipt.set_inner(TicketReceipt {
+            bonding_curve: self.bonding_curve.key(),
+            asset: self.asset.key(),
+            buyer: self.user.key(),
+            ticket_number,
+            curve_index,
+            price: lamports,
+            fee: total_fee,
+            purchased_at: now as i64,
+            bump: bumps.receipt,
         });
 
-        let collection_key = self.collection.key();
-
-        let seeds = &[
-            BondingCurve::SEED.as_bytes(),
-            collection_key.as_ref(),
-            &[self.bonding_curve.bump],
-        ];
-        let signer_seeds = &[&seeds[..]];
-
-        CreateV2CpiBuilder::new(&self.mpl_core_program.to_account_info())
-            .asset(&self.asset.to_account_info())
-            .name(("EVENTDOTFUN Ticket").to_owned())
-            .collection(Some(self.collection.as_ref()))
-            .uri("https://devnet.irys.xyz/2x17GpZTmXPKGGiUKuaQA5b9jg3tQuG7VquatBLdkFB2".to_owned())
-            .plugins(ticket_plugin)
-            .owner(Some(self.user.as_ref()))
-            .payer(&self.user.to_account_info())
-            .authority(Some(self.bonding_curve.as_ref()))
-            .system_program(&self.system_program.to_account_info())
-            .invoke_signed(signer_seeds)?;
-
-        Ok(())
+        // 6. create Core asset
+        TicketMint {
+            bonding_curve: &self.bonding_curve,
+            collection: &self.collection.to_account_info(),
+            user: &self.user.to_account_info(),
+            system_program: &cpi_program,
+            mpl_core_program: &self.mpl_core_program.to_account_info(),
+        }
+        .mint(&self.asset.to_account_info(), ticket_number, None)?;
+
+        Ok(TicketBought {
+            bonding_curve: self.bonding_curve.key(),
+            buyer: self.user.key(),
+            asset: self.asset.key(),
+            ticket_number,
+            price: lamports,
+            current_ticket_sold: self.bonding_curve.current_ticket_sold,
+            total_sol: self.bonding_curve.total_sol,
+            timestamp: now as i64,
+        })
     }
 }
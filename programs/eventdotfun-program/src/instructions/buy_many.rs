@@ -0,0 +1,316 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{
+        allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+    },
+};
+use mpl_core::{accounts::BaseCollectionV1, ID as MPL_CORE_ID};
+
+use super::shared::{pay_protocol_fee, TicketMint};
+use crate::{
+    error::ErrorCode, BondingCurve, Config, Referral, TicketBought, TicketReceipt,
+    MAX_TICKETS_PER_BUY,
+};
+
+/// Same as `Buy`, but the assets are PDAs derived from
+/// `[TICKET_SEED, bonding_curve, total_minted]` and passed, in order, as
+/// remaining accounts, each followed by its `TicketReceipt` PDA.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BuyMany<'info> {
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.paused && !bonding_curve.sales_paused @ ErrorCode::CurvePaused,
+        constraint = !bonding_curve.cancelled @ ErrorCode::EventCancelled
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(mut, seeds = [b"vault", bonding_curve.key().as_ref()], bump = bonding_curve.vault_bump)]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(mut, address = config.fee_recipient @ ErrorCode::InvalidFeeRecipient)]
+    pub fee_recipient: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [Referral::SEED.as_bytes(), referral.referrer.as_ref()],
+        bump = referral.bump,
+        constraint = referral.referrer != user.key() @ ErrorCode::InvalidReferrer
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+
+    #[account(mut, constraint = collection.key() == bonding_curve.collection)]
+    pub collection: Account<'info, BaseCollectionV1>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(address = MPL_CORE_ID)]
+    /// CHECK: this account is checked by the address constraint
+    pub mpl_core_program: UncheckedAccount<'info>,
+}
+
+impl<'info> BuyMany<'info> {
+    pub fn buy_many(
+        &mut self,
+        quantity: u64,
+        max_lamports: u64,
+        accounts: &[AccountInfo<'info>],
+    ) -> Result<Vec<TicketBought>> {
+        let current_ticket_sold = self.bonding_curve.current_ticket_sold;
+        let first_curve_index = current_ticket_sold
+            .checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        let last_curve_index = current_ticket_sold
+            .checked_add(quantity)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        let max_ticket_to_sold = self.bonding_curve.max_ticket_to_sold;
+
+        let now = Clock::get()?.unix_timestamp as u64;
+        let start_at = self.bonding_curve.start_at;
+        let end_at = self.bonding_curve.end_at;
+
+        require!(
+            quantity > 0 && quantity <= MAX_TICKETS_PER_BUY,
+            ErrorCode::InvalidQuantity
+        );
+        require!(
+            accounts.len() as u64 == quantity * 2,
+            ErrorCode::InvalidTicketAccount
+        );
+        require!(now >= start_at, ErrorCode::CurveNotStarted);
+        require!(now <= end_at, ErrorCode::CurveEnded);
+        require!(
+            last_curve_index <= max_ticket_to_sold,
+            ErrorCode::MaxTicketReached
+        );
+
+        // 1. calculate lamports, bounding what the buyer pays including the fee
+        let lamports = self.bonding_curve.price_for(first_curve_index, quantity)?;
+        let fee = self.config.fee_for(lamports)?;
+        require!(
+            lamports
+                .checked_add(fee)
+                .is_some_and(|total| total <= max_lamports),
+            ErrorCode::BuySlippageExceeded
+        );
+
+        // 2. transfer to vault
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_account = Transfer {
+            from: self.user.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_account);
+        transfer(cpi_ctx, lamports)?;
+
+        // 3. transfer protocol fee, crediting the referrer's cut
+        let total_fee = pay_protocol_fee(
+            &self.config,
+            lamports,
+            &self.user.to_account_info(),
+            &self.fee_recipient.to_account_info(),
+            self.referral.as_mut(),
+            &cpi_program,
+        )?;
+
+        // 4. create Core assets and receipts, and update bonding curve state.
+        // The fee was charged on the batch total, so each receipt records its
+        // pro-rata part and the last one the rounding remainder.
+        let mut fee_left = total_fee;
+        accounts
+            .chunks(2)
+            .enumerate()
+            .map(|(offset, pair)| {
+                let [asset, receipt] = pair else {
+                    return err!(ErrorCode::InvalidTicketAccount);
+                };
+                let curve_index = first_curve_index + offset as u64;
+                let fee = if curve_index == last_curve_index {
+                    fee_left
+                } else {
+                    let price = self.bonding_curve.price_at(curve_index)?;
+                    pro_rata(total_fee, price, lamports)?
+                };
+                fee_left = fee_left
+                    .checked_sub(fee)
+                    .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+                self.mint_ticket(asset, receipt, curve_index, fee, now as i64)
+            })
+            .collect()
+    }
+
+    fn mint_ticket(
+        &mut self,
+        asset: &AccountInfo<'info>,
+        receipt: &AccountInfo<'info>,
+        curve_index: u64,
+        fee: u64,
+        timestamp: i64,
+    ) -> Result<TicketBought> {
+        let lamports = self.bonding_curve.price_at(curve_index)?;
+        let ticket_number = self
+            .bonding_curve
+            .total_minted
+            .checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        let bonding_curve_key = self.bonding_curve.key();
+        let nonce = self.bonding_curve.total_minted.to_le_bytes();
+
+        let (expected_asset, asset_bump) = Pubkey::find_program_address(
+            &[
+                BondingCurve::TICKET_SEED.as_bytes(),
+                bonding_curve_key.as_ref(),
+                &nonce,
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(asset.key(), expected_asset, ErrorCode::InvalidTicketAccount);
+
+        let asset_seeds: &[&[u8]] = &[
+            BondingCurve::TICKET_SEED.as_bytes(),
+            bonding_curve_key.as_ref(),
+            &nonce,
+            &[asset_bump],
+        ];
+        TicketMint {
+            bonding_curve: &self.bonding_curve,
+            collection: &self.collection.to_account_info(),
+            user: &self.user.to_account_info(),
+            system_program: &self.system_program.to_account_info(),
+            mpl_core_program: &self.mpl_core_program.to_account_info(),
+        }
+        .mint(asset, ticket_number, Some(asset_seeds))?;
+
+        self.create_receipt(
+            asset,
+            receipt,
+            ticket_number,
+            curve_index,
+            lamports,
+            fee,
+            timestamp,
+        )?;
+
+        self.bonding_curve.record_buy(lamports)?;
+
+        Ok(TicketBought {
+            bonding_curve: bonding_curve_key,
+            buyer: self.user.key(),
+            asset: asset.key(),
+            ticket_number,
+            price: lamports,
+            current_ticket_sold: self.bonding_curve.current_ticket_sold,
+            total_sol: self.bonding_curve.total_sol,
+            timestamp,
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_receipt(
+        &self,
+        asset: &AccountInfo<'info>,
+        receipt: &AccountInfo<'info>,
+        ticket_number: u64,
+        curve_index: u64,
+        lamports: u64,
+        fee: u64,
+        timestamp: i64,
+    ) -> Result<()> {
+        let asset_key = asset.key();
+
+        let (expected_receipt, receipt_bump) = Pubkey::find_program_address(
+            &[TicketReceipt::SEED.as_bytes(), asset_key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            receipt.key(),
+            expected_receipt,
+            ErrorCode::InvalidTicketAccount
+        );
+
+        let receipt_seeds = &[
+            TicketReceipt::SEED.as_bytes(),
+            asset_key.as_ref(),
+            &[receipt_bump],
+        ];
+        let signer_seeds = &[&receipt_seeds[..]];
+        let cpi_program = self.system_program.to_account_info();
+        let rent = Rent::get()?.minimum_balance(TicketReceipt::INIT_SPACE);
+        let current_lamports = receipt.lamports();
+
+        // same as Anchor's `init`: someone may have pre-funded the address,
+        // which would make `create_account` fail
+        if current_lamports == 0 {
+            let cpi_account = CreateAccount {
+                from: self.user.to_account_info(),
+                to: receipt.clone(),
+            };
+            let cpi_ctx =
+                CpiContext::new_with_signer(cpi_program.clone(), cpi_account, signer_seeds);
+            create_account(cpi_ctx, rent, TicketReceipt::INIT_SPACE as u64, &crate::ID)?;
+        } else {
+            let top_up = rent.saturating_sub(current_lamports);
+            if top_up > 0 {
+                let cpi_account = Transfer {
+                    from: self.user.to_account_info(),
+                    to: receipt.clone(),
+                };
+                let cpi_ctx = CpiContext::new(cpi_program.clone(), cpi_account);
+                transfer(cpi_ctx, top_up)?;
+            }
+
+            let cpi_account = Allocate {
+                account_to_allocate: receipt.clone(),
+            };
+            let cpi_ctx =
+                CpiContext::new_with_signer(cpi_program.clone(), cpi_account, signer_seeds);
+            allocate(cpi_ctx, TicketReceipt::INIT_SPACE as u64)?;
+
+            let cpi_account = Assign {
+                account_to_assign: receipt.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account, signer_seeds);
+            assign(cpi_ctx, &crate::ID)?;
+        }
+
+        let ticket_receipt = TicketReceipt {
+            bonding_curve: self.bonding_curve.key(),
+            asset: asset_key,
+            buyer: self.user.key(),
+            ticket_number,
+            curve_index,
+            price: lamports,
+            fee,
+            purchased_at: timestamp,
+            bump: receipt_bump,
+        };
+        let mut data = receipt.try_borrow_mut_data()?;
+        ticket_receipt.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
+
+/// Part of `fee`, charged on `total` lamports, attributable to `lamports`.
+fn pro_rata(fee: u64, lamports: u64, total: u64) -> Result<u64> {
+    let share = (fee as u128)
+        .checked_mul(lamports as u128)
+        .and_then(|scaled| scaled.checked_div(total as u128))
+        .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+    Ok(share as u64)
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BondingCurve, Config, EventCancelled, EventInfo};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelEvent<'info> {
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.cancelled @ ErrorCode::EventCancelled
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        seeds = [EventInfo::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump = event_info.bump
+    )]
+    pub event_info: Account<'info, EventInfo>,
+
+    #[account(seeds = [Config::SEED.as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: only used to derive the bonding curve address
+    #[account(constraint = collection.key() == bonding_curve.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = user.key() == bonding_curve.creator || user.key() == config.admin @ ErrorCode::InvalidAuthority
+    )]
+    pub user: Signer<'info>,
+}
+
+impl<'info> CancelEvent<'info> {
+    pub fn cancel_event(&mut self) -> Result<EventCancelled> {
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        // an event that already took place can't be called off
+        require!(
+            now < self.event_info.details.event_start_at,
+            ErrorCode::EventAlreadyStarted
+        );
+
+        self.bonding_curve.cancelled = true;
+
+        Ok(EventCancelled {
+            bonding_curve: self.bonding_curve.key(),
+            authority: self.user.key(),
+            timestamp: now as i64,
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Referral, ReferralClaimed};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimReferral<'info> {
+    #[account(
+        mut,
+        seeds = [Referral::SEED.as_bytes(), user.key().as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    #[account(mut, constraint = user.key() == referral.referrer @ ErrorCode::InvalidReferrer)]
+    pub user: Signer<'info>,
+}
+
+impl<'info> ClaimReferral<'info> {
+    pub fn claim_referral(&mut self) -> Result<ReferralClaimed> {
+        let lamports = self
+            .referral
+            .total_earned
+            .checked_sub(self.referral.total_claimed)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        require!(lamports > 0, ErrorCode::NothingToClaim);
+
+        // the referral PDA is owned by this program, so lamports are moved
+        // directly instead of through the system program
+        let referral_info = self.referral.to_account_info();
+        let user_info = self.user.to_account_info();
+        let referral_lamports = referral_info
+            .lamports()
+            .checked_sub(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        let user_lamports = user_info
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        **referral_info.try_borrow_mut_lamports()? = referral_lamports;
+        **user_info.try_borrow_mut_lamports()? = user_lamports;
+
+        self.referral.total_claimed = self
+            .referral
+            .total_claimed
+            .checked_add(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        Ok(ReferralClaimed {
+            referral: self.referral.key(),
+            referrer: self.user.key(),
+            lamports,
+            total_claimed: self.referral.total_claimed,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
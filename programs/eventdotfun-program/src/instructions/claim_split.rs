@@ -0,0 +1,83 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{error::ErrorCode, BondingCurve, Config, RevenueSplit, SplitClaimed};
+
+/// Lets a split recipient collect the shares `withdraw` couldn't deliver.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimSplit<'info> {
+    #[account(
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.paused @ ErrorCode::CurvePaused
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(mut, seeds = [b"vault", bonding_curve.key().as_ref()], bump = bonding_curve.vault_bump)]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [RevenueSplit::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump = revenue_split.bump
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    /// CHECK: only used to derive the bonding curve address
+    #[account(constraint = collection.key() == bonding_curve.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimSplit<'info> {
+    pub fn claim_split(&mut self) -> Result<SplitClaimed> {
+        let index = self
+            .revenue_split
+            .recipients
+            .iter()
+            .position(|split| split.recipient == self.user.key())
+            .ok_or(error!(ErrorCode::InvalidSplitRecipient))?;
+
+        let lamports = self.revenue_split.owed[index];
+        require!(lamports > 0, ErrorCode::NothingToClaim);
+        self.revenue_split.owed[index] = 0;
+
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_account = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.user.to_account_info(),
+        };
+
+        let bonding_curve_key = self.bonding_curve.key();
+        let seeds = &[
+            b"vault",
+            bonding_curve_key.as_ref(),
+            &[self.bonding_curve.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account, signer_seeds);
+        transfer(cpi_ctx, lamports)?;
+
+        Ok(SplitClaimed {
+            bonding_curve: bonding_curve_key,
+            recipient: self.user.key(),
+            lamports,
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::{error::ErrorCode, BondingCurve, CurveClosed, EventInfo, RevenueSplit};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBondingCurve<'info> {
+    #[account(
+        mut,
+        close = user,
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(mut, seeds = [b"vault", bonding_curve.key().as_ref()], bump = bonding_curve.vault_bump)]
+    pub vault: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [RevenueSplit::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump = revenue_split.bump
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [EventInfo::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump = event_info.bump
+    )]
+    pub event_info: Account<'info, EventInfo>,
+
+    /// CHECK: only used to derive the bonding curve address
+    #[account(constraint = collection.key() == bonding_curve.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = user.key() == bonding_curve.creator @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseBondingCurve<'info> {
+    pub fn close_bonding_curve(&mut self) -> Result<CurveClosed> {
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        require!(
+            now >= self.bonding_curve.end_at,
+            ErrorCode::CurveStillOnProgress
+        );
+
+        // 1. make sure nothing is still owed to holders or split recipients
+        require!(
+            self.revenue_split.total_owed()? == 0,
+            ErrorCode::ObligationsOutstanding
+        );
+        if self.bonding_curve.is_refundable(now)? {
+            require!(
+                self.bonding_curve.total_reserved == 0,
+                ErrorCode::ObligationsOutstanding
+            );
+        } else {
+            let vesting_ends_at = self
+                .bonding_curve
+                .vesting
+                .ends_at(self.event_info.details.event_end_at)?;
+            require!(now >= vesting_ends_at, ErrorCode::VestingInProgress);
+            require!(
+                self.vault.lamports() <= self.bonding_curve.required_vault_lamports(now)?,
+                ErrorCode::ObligationsOutstanding
+            );
+        }
+
+        // 2. sweep the vault to the creator
+        let lamports = self.vault.lamports();
+
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_account = Transfer {
+            from: self.vault.to_account_info(),
+            to: self.user.to_account_info(),
+        };
+
+        let bonding_curve_key = self.bonding_curve.key();
+        let seeds = &[
+            b"vault",
+            bonding_curve_key.as_ref(),
+            &[self.bonding_curve.vault_bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_account, signer_seeds);
+        transfer(cpi_ctx, lamports)?;
+
+        Ok(CurveClosed {
+            bonding_curve: bonding_curve_key,
+            creator: self.user.key(),
+            lamports,
+            timestamp: now as i64,
+        })
+    }
+}
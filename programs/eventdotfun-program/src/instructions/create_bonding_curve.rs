@@ -1,10 +1,16 @@
-use anchor_lang::prelude::*;
-use mpl_core::{
-    instructions::{CreateCollectionV2CpiBuilder, CreateV2CpiBuilder},
-    ID as MPL_CORE_ID,
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
 };
+use mpl_core::{instructions::CreateCollectionV2CpiBuilder, ID as MPL_CORE_ID};
 
-use crate::{error::ErrorCode, BondingCurve};
+use crate::{
+    curve::{CurveKind, PricingCurve},
+    error::ErrorCode,
+    BondingCurve, CurveCreated, EventDetails, EventInfo, EventMetadata, RevenueSplit, SalesType,
+    SellPolicy, SplitRecipient, VestingSchedule,
+};
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CreateBondingCurve<'info> {
     #[account(
@@ -19,6 +25,24 @@ pub struct CreateBondingCurve<'info> {
     #[account(mut, seeds = [b"vault", bonding_curve.key().as_ref()], bump)]
     pub vault: SystemAccount<'info>,
 
+    #[account(
+        init,
+        space = RevenueSplit::INIT_SPACE,
+        payer = user,
+        seeds = [RevenueSplit::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    #[account(
+        init,
+        space = EventInfo::INIT_SPACE,
+        payer = user,
+        seeds = [EventInfo::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    pub event_info: Account<'info, EventInfo>,
+
     #[account(mut)]
     pub collection: Signer<'info>,
 
@@ -33,23 +57,33 @@ pub struct CreateBondingCurve<'info> {
 }
 
 impl<'info> CreateBondingCurve<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn create_bonding_curve(
         &mut self,
-        sales_type: u8,
+        sales_type: SalesType,
         start_at: u64,
         end_at: u64,
-        exponent: u8,
+        curve: CurveKind,
         initial_price: u64,
         last_price: u64,
         min_ticket_to_sold: u64,
         max_ticket_to_sold: u64,
         refund_window: u64,
+        refund_period: u64,
+        sell_policy: SellPolicy,
+        vesting: VestingSchedule,
+        revenue_splits: Vec<SplitRecipient>,
+        metadata: EventMetadata,
+        details: EventDetails,
         bumps: &CreateBondingCurveBumps,
-    ) -> Result<()> {
-        require!(
-            sales_type > 0 || sales_type < 3,
-            ErrorCode::InvalidSalesType
-        );
+    ) -> Result<CurveCreated> {
+        // threshold sales refund on their own schedule, so a window is
+        // meaningless there, while refund-window sales need a bounded one
+        let valid_refund_window = match sales_type {
+            SalesType::Threshold => refund_window == 0 && refund_period == 0,
+            SalesType::RefundWindow => refund_period > 0,
+        };
+        require!(valid_refund_window, ErrorCode::InvalidRefundWindow);
 
         let now = Clock::get()?.unix_timestamp as u64;
         require!(
@@ -57,17 +91,22 @@ impl<'info> CreateBondingCurve<'info> {
             ErrorCode::InvalidTimestamp
         );
 
-        require!(exponent > 0 || exponent < 3, ErrorCode::InvalidExponent);
         require!(
-            initial_price > 0 || last_price >= initial_price,
+            initial_price > 0 && last_price >= initial_price,
             ErrorCode::InvalidPrice
         );
         require!(
-            min_ticket_to_sold < max_ticket_to_sold,
+            min_ticket_to_sold > 0 && min_ticket_to_sold < max_ticket_to_sold,
             ErrorCode::InvalidTicketConfiguration
         );
+        curve.validate(min_ticket_to_sold)?;
+        RevenueSplit::validate(&revenue_splits)?;
+        metadata.validate()?;
+        details.validate(max_ticket_to_sold, end_at)?;
+        vesting.validate(details.event_end_at)?;
 
-        let multiplier = (last_price - initial_price) / (min_ticket_to_sold ^ exponent as u64);
+        let collection_name = metadata.name.clone();
+        let collection_uri = metadata.uri.clone();
 
         self.bonding_curve.set_inner(BondingCurve {
             creator: self.user.key(),
@@ -75,29 +114,73 @@ impl<'info> CreateBondingCurve<'info> {
             start_at,
             end_at,
             collection: self.collection.key(),
-            exponent,
+            metadata,
+            curve,
             initial_price,
             last_price,
-            multiplier,
             max_ticket_to_sold,
             current_ticket_sold: 0,
             min_ticket_to_sold,
+            total_minted: 0,
             total_sol: 0,
             total_refund: 0,
+            total_withdrawn: 0,
+            total_reserved: 0,
             refund_window,
+            refund_period,
+            sell_policy,
+            vesting,
+            paused: false,
+            sales_paused: false,
+            paused_at: 0,
+            refund_extension: 0,
+            cancelled: false,
             bump: bumps.bonding_curve,
             vault_bump: bumps.vault,
         });
 
+        self.revenue_split.set_inner(RevenueSplit {
+            bonding_curve: self.bonding_curve.key(),
+            owed: vec![0; revenue_splits.len()],
+            recipients: revenue_splits,
+            bump: bumps.revenue_split,
+        });
+
+        self.event_info.set_inner(EventInfo {
+            bonding_curve: self.bonding_curve.key(),
+            details,
+            bump: bumps.event_info,
+        });
+
+        // fund the vault's rent-exempt minimum so payouts never dip below it
+        let cpi_account = Transfer {
+            from: self.user.to_account_info(),
+            to: self.vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(self.system_program.to_account_info(), cpi_account);
+        transfer(cpi_ctx, Rent::get()?.minimum_balance(0))?;
+
         CreateCollectionV2CpiBuilder::new(&self.mpl_core_program.to_account_info())
             .collection(&self.collection.to_account_info())
             .payer(&self.user.to_account_info())
             .update_authority(Some(self.bonding_curve.as_ref()))
             .system_program(&self.system_program.to_account_info())
-            .name("EVENTDOTFUN Collection".to_owned())
-            .uri("https://devnet.irys.xyz/2x17GpZTmXPKGGiUKuaQA5b9jg3tQuG7VquatBLdkFB2".to_owned())
+            .name(collection_name)
+            .uri(collection_uri)
             .invoke()?;
 
-        Ok(())
+        Ok(CurveCreated {
+            bonding_curve: self.bonding_curve.key(),
+            collection: self.collection.key(),
+            creator: self.user.key(),
+            sales_type,
+            start_at,
+            end_at,
+            initial_price,
+            last_price,
+            min_ticket_to_sold,
+            max_ticket_to_sold,
+            timestamp: now as i64,
+        })
     }
 }
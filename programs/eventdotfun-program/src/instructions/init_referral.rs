@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::{Referral, ReferralCreated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitReferral<'info> {
+    #[account(
+        init,
+        space = Referral::INIT_SPACE,
+        payer = user,
+        seeds = [Referral::SEED.as_bytes(), user.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitReferral<'info> {
+    pub fn init_referral(&mut self, bumps: &InitReferralBumps) -> Result<ReferralCreated> {
+        self.referral.set_inner(Referral {
+            referrer: self.user.key(),
+            total_earned: 0,
+            total_claimed: 0,
+            bump: bumps.referral,
+        });
+
+        Ok(ReferralCreated {
+            referral: self.referral.key(),
+            referrer: self.user.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        })
+    }
+}
@@ -0,0 +1,53 @@
+use anchor_lang::{prelude::*, solana_program::bpf_loader_upgradeable};
+
+use crate::{error::ErrorCode, Config, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        space = Config::INIT_SPACE,
+        payer = user,
+        seeds = [Config::SEED.as_bytes()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [crate::ID.as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID,
+        constraint = program_data.upgrade_authority_address == Some(user.key()) @ ErrorCode::InvalidAuthority
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Initialize<'info> {
+    pub fn initialize_config(
+        &mut self,
+        fee: u64,
+        fee_recipient: Pubkey,
+        referral_fee: u64,
+        bumps: &InitializeBumps,
+    ) -> Result<ConfigUpdated> {
+        Config::validate(fee, fee_recipient, referral_fee)?;
+
+        self.config.set_inner(Config {
+            admin: self.user.key(),
+            pending_admin: None,
+            fee,
+            fee_recipient,
+            referral_fee,
+            paused: false,
+            bump: bumps.config,
+        });
+
+        ConfigUpdated::from_config(&self.config)
+    }
+}
@@ -1,13 +1,40 @@
+pub mod accept_admin;
 pub mod buy;
+pub mod buy_many;
+pub mod cancel_event;
+pub mod claim_referral;
+pub mod claim_split;
+pub mod close_bonding_curve;
 pub mod create_bonding_curve;
+pub mod init_referral;
+pub mod initialize;
+pub mod propose_admin;
+pub mod quote;
 pub mod refund;
 pub mod sell;
-pub mod setup_config;
+pub mod set_curve_paused;
+pub mod set_paused;
+mod shared;
+pub mod update_config;
+pub mod update_event_info;
 pub mod withdraw;
 
+pub use accept_admin::*;
 pub use buy::*;
+pub use buy_many::*;
+pub use cancel_event::*;
+pub use claim_referral::*;
+pub use claim_split::*;
+pub use close_bonding_curve::*;
 pub use create_bonding_curve::*;
+pub use init_referral::*;
+pub use initialize::*;
+pub use propose_admin::*;
+pub use quote::*;
 pub use refund::*;
 pub use sell::*;
-pub use setup_config::*;
+pub use set_curve_paused::*;
+pub use set_paused::*;
+pub use update_config::*;
+pub use update_event_info::*;
 pub use withdraw::*;
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Config, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, seeds = [Config::SEED.as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = user.key() == config.admin @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+}
+
+impl<'info> ProposeAdmin<'info> {
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<ConfigUpdated> {
+        self.config.pending_admin = Some(new_admin);
+
+        ConfigUpdated::from_config(&self.config)
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BondingCurve, Config, TicketReceipt, MAX_TICKETS_PER_BUY};
+
+/// Read-only price lookups. The result is returned through
+/// `set_return_data`, so clients can simulate these instead of re-implementing
+/// the curve off-chain.
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    /// CHECK: only used to derive the bonding curve address
+    #[account(constraint = collection.key() == bonding_curve.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(seeds = [Config::SEED.as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct QuoteSell<'info> {
+    #[account(
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    /// CHECK: only used to derive the bonding curve address
+    #[account(constraint = collection.key() == bonding_curve.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(constraint = receipt.bonding_curve == bonding_curve.key() @ ErrorCode::InvalidTicketAccount)]
+    pub receipt: Account<'info, TicketReceipt>,
+}
+
+impl<'info> Quote<'info> {
+    /// What `buy_many` would charge for `quantity` tickets, fee included.
+    /// Applies the same quantity and supply bounds, but not the sale window
+    /// or pause checks.
+    pub fn quote_buy(&self, quantity: u64) -> Result<u64> {
+        let current_ticket_sold = self.bonding_curve.current_ticket_sold;
+        let max_ticket_to_sold = self.bonding_curve.max_ticket_to_sold;
+
+        require!(
+            quantity > 0 && quantity <= MAX_TICKETS_PER_BUY,
+            ErrorCode::InvalidQuantity
+        );
+        require!(
+            current_ticket_sold
+                .checked_add(quantity)
+                .is_some_and(|last_ticket| last_ticket <= max_ticket_to_sold),
+            ErrorCode::MaxTicketReached
+        );
+
+        let lamports = self
+            .bonding_curve
+            .price_for(current_ticket_sold + 1, quantity)?;
+        let fee = self.config.fee_for(lamports)?;
+
+        lamports
+            .checked_add(fee)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+}
+
+impl<'info> QuoteSell<'info> {
+    /// What `sell` would pay for the ticket behind `receipt` right now.
+    pub fn quote_sell(&self) -> Result<u64> {
+        let now = Clock::get()?.unix_timestamp as u64;
+        self.bonding_curve.ensure_sell_open(now)?;
+
+        self.bonding_curve.sell_price(&self.receipt)
+    }
+}
@@ -4,20 +4,23 @@ use anchor_lang::{
 };
 use mpl_core::{
     accounts::{BaseAssetV1, BaseCollectionV1},
-    fetch_plugin,
-    instructions::{BurnV1CpiBuilder, CreateV2CpiBuilder},
-    types::{Attributes, PluginType},
+    instructions::BurnV1CpiBuilder,
+    types::UpdateAuthority,
     ID as MPL_CORE_ID,
 };
 
-use crate::{error::ErrorCode, BondingCurve};
+use crate::{error::ErrorCode, BondingCurve, Refunded, SalesType, TicketReceipt};
 
+/// Not gated by the protocol pause: only the admin's curve pause, which holds
+/// the refund period open, can halt refunds.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Refund<'info> {
     #[account(
         mut,
         seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
-        bump = bonding_curve.bump
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.paused @ ErrorCode::CurvePaused
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
@@ -28,9 +31,23 @@ pub struct Refund<'info> {
     #[account(mut, constraint = collection.key() == bonding_curve.collection)]
     pub collection: Account<'info, BaseCollectionV1>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = asset.owner == user.key() @ ErrorCode::InvalidAssetOwner,
+        constraint = asset.update_authority == UpdateAuthority::Collection(bonding_curve.collection)
+            @ ErrorCode::InvalidAssetCollection
+    )]
     pub asset: Account<'info, BaseAssetV1>,
 
+    #[account(
+        mut,
+        close = user,
+        seeds = [TicketReceipt::SEED.as_bytes(), asset.key().as_ref()],
+        bump = receipt.bump,
+        constraint = receipt.bonding_curve == bonding_curve.key() @ ErrorCode::InvalidTicketAccount
+    )]
+    pub receipt: Account<'info, TicketReceipt>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -42,49 +59,37 @@ pub struct Refund<'info> {
 }
 
 impl<'info> Refund<'info> {
-    pub fn refund(&mut self) -> Result<()> {
+    pub fn refund(&mut self) -> Result<Refunded> {
         let now = Clock::get()?.unix_timestamp as u64;
         let end = self.bonding_curve.end_at;
         let current_ticket_to_sold = self.bonding_curve.current_ticket_sold;
         let min_ticket_to_sold = self.bonding_curve.min_ticket_to_sold;
         let sales_type = self.bonding_curve.sales_type;
 
-        if sales_type == 1 {
-            require!(now >= end, ErrorCode::CurveStillOnProgress);
-            require!(
-                current_ticket_to_sold < min_ticket_to_sold,
-                ErrorCode::CurveReachesThreshold
-            );
-
-            BurnV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
-                .asset(&self.asset.to_account_info())
-                .collection(Some(self.collection.as_ref()))
-                .authority(Some(&self.user.to_account_info()))
-                .payer(&self.user.to_account_info())
-                .system_program(Some(&self.system_program.to_account_info()))
-                .invoke()?;
-        } else if sales_type == 2 {
-            let refund_at = end + self.bonding_curve.refund_window;
-            require!(now >= refund_at, ErrorCode::RefundNotOpened);
+        // a cancelled event refunds every ticket straight away
+        if !self.bonding_curve.cancelled {
+            match sales_type {
+                SalesType::Threshold => {
+                    // strictly after the sale, so a ticket can't be both sold and refunded
+                    require!(now > end, ErrorCode::CurveStillOnProgress);
+                    require!(
+                        current_ticket_to_sold < min_ticket_to_sold,
+                        ErrorCode::CurveReachesThreshold
+                    );
+                }
+                SalesType::RefundWindow => {
+                    let (opens_at, closes_at) = self.bonding_curve.refund_period_bounds(now)?;
+                    require!(now > end && now >= opens_at, ErrorCode::RefundNotOpened);
+                    require!(now < closes_at, ErrorCode::RefundClosed);
+                }
+            }
         }
 
-        let (_, asset_attribute_list, _) = fetch_plugin::<BaseAssetV1, Attributes>(
-            &self.asset.to_account_info(),
-            PluginType::Attributes,
-        )?;
-
-        let ticket_number_attribute = asset_attribute_list
-            .attribute_list
-            .iter()
-            .find(|attr| attr.key == "Ticket Number")
-            .unwrap();
+        // a refunded ticket must not still get its holder in
+        self.burn_asset()?;
 
-        let ticket_number = ticket_number_attribute.value.parse::<u64>().unwrap();
-
-        let initial_price = self.bonding_curve.initial_price;
-        let exponent = self.bonding_curve.exponent;
-        let multiplier = self.bonding_curve.multiplier;
-        let lamports = initial_price + (ticket_number ^ exponent as u64) * multiplier;
+        let ticket_number = self.receipt.ticket_number;
+        let lamports = self.receipt.price;
 
         let cpi_program = self.system_program.to_account_info();
         let cpi_account = Transfer {
@@ -103,7 +108,29 @@ impl<'info> Refund<'info> {
         let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_account, signer_seeds);
         transfer(cpi_ctx, lamports)?;
 
-        self.bonding_curve.total_refund += lamports;
+        self.bonding_curve.record_refund(lamports)?;
+        self.bonding_curve
+            .ensure_solvent(self.vault.lamports(), now)?;
+
+        Ok(Refunded {
+            bonding_curve: bonding_curve_key,
+            user: self.user.key(),
+            asset: self.asset.key(),
+            ticket_number,
+            lamports,
+            total_refund: self.bonding_curve.total_refund,
+            timestamp: now as i64,
+        })
+    }
+
+    fn burn_asset(&self) -> Result<()> {
+        BurnV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .asset(&self.asset.to_account_info())
+            .collection(Some(self.collection.as_ref()))
+            .authority(Some(&self.user.to_account_info()))
+            .payer(&self.user.to_account_info())
+            .system_program(Some(&self.system_program.to_account_info()))
+            .invoke()?;
 
         Ok(())
     }
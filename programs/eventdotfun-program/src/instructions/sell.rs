@@ -2,38 +2,56 @@ use anchor_lang::{
     prelude::*,
     system_program::{transfer, Transfer},
 };
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token_interface::{TokenAccount, TokenInterface},
-};
 use mpl_core::{
     accounts::{BaseAssetV1, BaseCollectionV1},
-    fetch_plugin,
-    instructions::{BurnV1CpiBuilder, CreateV2CpiBuilder},
-    types::{Attributes, PluginType},
+    instructions::BurnV1CpiBuilder,
+    types::UpdateAuthority,
     ID as MPL_CORE_ID,
 };
 
-use crate::{error::ErrorCode, BondingCurve};
+use crate::{error::ErrorCode, BondingCurve, Config, TicketReceipt, TicketSold};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Sell<'info> {
     #[account(
         mut,
         seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
-        bump = bonding_curve.bump
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.paused @ ErrorCode::CurvePaused
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
     #[account(mut, seeds = [b"vault", bonding_curve.key().as_ref()], bump = bonding_curve.vault_bump)]
     pub vault: SystemAccount<'info>,
 
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(mut, constraint = collection.key() == bonding_curve.collection)]
     pub collection: Account<'info, BaseCollectionV1>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = asset.owner == user.key() @ ErrorCode::InvalidAssetOwner,
+        constraint = asset.update_authority == UpdateAuthority::Collection(bonding_curve.collection)
+            @ ErrorCode::InvalidAssetCollection
+    )]
     pub asset: Account<'info, BaseAssetV1>,
 
+    #[account(
+        mut,
+        close = user,
+        seeds = [TicketReceipt::SEED.as_bytes(), asset.key().as_ref()],
+        bump = receipt.bump,
+        constraint = receipt.bonding_curve == bonding_curve.key() @ ErrorCode::InvalidTicketAccount
+    )]
+    pub receipt: Account<'info, TicketReceipt>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -45,28 +63,17 @@ pub struct Sell<'info> {
 }
 
 impl<'info> Sell<'info> {
-    pub fn sell(&mut self) -> Result<()> {
-        let ticket_index = self.bonding_curve.current_ticket_sold;
-        let initial_price = self.bonding_curve.initial_price;
-        let exponent = self.bonding_curve.exponent;
-        let multiplier = self.bonding_curve.multiplier;
-        let min_ticket_to_sold = self.bonding_curve.min_ticket_to_sold;
-
+    pub fn sell(&mut self, min_lamports: u64) -> Result<TicketSold> {
         let now = Clock::get()?.unix_timestamp as u64;
-        let end_at = self.bonding_curve.end_at;
-
-        require!(now <= end_at, ErrorCode::CurveEnded);
-        require!(
-            ticket_index < min_ticket_to_sold,
-            ErrorCode::CurveReachesThreshold
-        );
+        self.bonding_curve.ensure_sell_open(now)?;
 
-        // 1. calculate lamports
-        let lamports = initial_price + ((ticket_index - 1) ^ exponent as u64) * multiplier;
+        // 1. price the ticket according to the curve's sell-back policy
+        let lamports = self.bonding_curve.sell_price(&self.receipt)?;
+        require!(lamports >= min_lamports, ErrorCode::SellSlippageExceeded);
 
         // 2. update bonding curve state
-        self.bonding_curve.current_ticket_sold -= 1_u64;
-        self.bonding_curve.total_sol -= lamports;
+        self.bonding_curve
+            .record_sell(lamports, self.receipt.price)?;
 
         // 3. transfer from vault
         let cpi_program = self.system_program.to_account_info();
@@ -85,6 +92,8 @@ impl<'info> Sell<'info> {
 
         let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_account, signer_seeds);
         transfer(cpi_ctx, lamports)?;
+        self.bonding_curve
+            .ensure_solvent(self.vault.lamports(), now)?;
 
         // 4. burn Core asset
         BurnV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
@@ -95,6 +104,15 @@ impl<'info> Sell<'info> {
             .system_program(Some(&self.system_program.to_account_info()))
             .invoke()?;
 
-        Ok(())
+        Ok(TicketSold {
+            bonding_curve: bonding_curve_key,
+            seller: self.user.key(),
+            asset: self.asset.key(),
+            ticket_number: self.receipt.ticket_number,
+            price: lamports,
+            current_ticket_sold: self.bonding_curve.current_ticket_sold,
+            total_sol: self.bonding_curve.total_sol,
+            timestamp: now as i64,
+        })
     }
 }
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BondingCurve, Config, CurvePauseUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCurvePaused<'info> {
+    #[account(
+        mut,
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(seeds = [Config::SEED.as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: only used to derive the bonding curve address
+    #[account(constraint = collection.key() == bonding_curve.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = user.key() == bonding_curve.creator || user.key() == config.admin @ ErrorCode::InvalidAuthority
+    )]
+    pub user: Signer<'info>,
+}
+
+impl<'info> SetCurvePaused<'info> {
+    /// The admin halts the whole curve; the creator can only stop new sales,
+    /// so holders can always get their refunds.
+    pub fn set_curve_paused(&mut self, paused: bool) -> Result<CurvePauseUpdated> {
+        let now = Clock::get()?.unix_timestamp as u64;
+
+        if self.user.key() == self.config.admin {
+            self.bonding_curve.set_paused(paused, now)?;
+        } else {
+            self.bonding_curve.sales_paused = paused;
+        }
+
+        Ok(CurvePauseUpdated {
+            bonding_curve: self.bonding_curve.key(),
+            authority: self.user.key(),
+            paused: self.bonding_curve.paused,
+            sales_paused: self.bonding_curve.sales_paused,
+            timestamp: now as i64,
+        })
+    }
+}
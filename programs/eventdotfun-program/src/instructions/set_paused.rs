@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Config, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    #[account(mut, seeds = [Config::SEED.as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = user.key() == config.admin @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+}
+
+impl<'info> SetPaused<'info> {
+    pub fn set_paused(&mut self, paused: bool) -> Result<ConfigUpdated> {
+        self.config.paused = paused;
+
+        ConfigUpdated::from_config(&self.config)
+    }
+}
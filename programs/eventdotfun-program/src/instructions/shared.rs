@@ -0,0 +1,116 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+use mpl_core::{
+    instructions::CreateV2CpiBuilder,
+    types::{Attribute, Attributes, Plugin, PluginAuthority, PluginAuthorityPair},
+};
+
+use crate::{error::ErrorCode, BondingCurve, Config, Referral};
+
+/// Charges `user` the protocol fee on `lamports`, crediting the referrer's
+/// cut to `referral`. Returns the whole fee charged.
+pub(crate) fn pay_protocol_fee<'info>(
+    config: &Config,
+    lamports: u64,
+    user: &AccountInfo<'info>,
+    fee_recipient: &AccountInfo<'info>,
+    referral: Option<&mut Account<'info, Referral>>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let total_fee = config.fee_for(lamports)?;
+    let mut fee = total_fee;
+
+    if let Some(referral) = referral {
+        let reward = config.referral_fee_for(lamports)?;
+        if reward > 0 {
+            let cpi_account = Transfer {
+                from: user.clone(),
+                to: referral.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.clone(), cpi_account);
+            transfer(cpi_ctx, reward)?;
+
+            referral.total_earned = referral
+                .total_earned
+                .checked_add(reward)
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+            fee = fee
+                .checked_sub(reward)
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        }
+    }
+
+    if fee > 0 {
+        let cpi_account = Transfer {
+            from: user.clone(),
+            to: fee_recipient.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_account);
+        transfer(cpi_ctx, fee)?;
+    }
+
+    Ok(total_fee)
+}
+
+/// Accounts needed to mint a ticket into the curve's collection.
+pub(crate) struct TicketMint<'a, 'info> {
+    pub bonding_curve: &'a Account<'info, BondingCurve>,
+    pub collection: &'a AccountInfo<'info>,
+    pub user: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub mpl_core_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> TicketMint<'a, 'info> {
+    /// Creates Core asset `ticket_number` owned by `user`. `asset_seeds`
+    /// signs for assets that are PDAs rather than keypairs.
+    pub fn mint(
+        &self,
+        asset: &AccountInfo<'info>,
+        ticket_number: u64,
+        asset_seeds: Option<&[&[u8]]>,
+    ) -> Result<()> {
+        let metadata = &self.bonding_curve.metadata;
+
+        let attribute_list: Vec<Attribute> = vec![
+            Attribute {
+                key: "Ticket Number".to_string(),
+                value: ticket_number.to_string(),
+            },
+            Attribute {
+                key: "Symbol".to_string(),
+                value: metadata.symbol.clone(),
+            },
+        ];
+
+        let ticket_plugin = vec![PluginAuthorityPair {
+            plugin: Plugin::Attributes(Attributes { attribute_list }),
+            authority: Some(PluginAuthority::UpdateAuthority),
+        }];
+
+        let collection_key = self.collection.key();
+        let bonding_curve_seeds: &[&[u8]] = &[
+            BondingCurve::SEED.as_bytes(),
+            collection_key.as_ref(),
+            &[self.bonding_curve.bump],
+        ];
+        let mut signer_seeds = vec![bonding_curve_seeds];
+        signer_seeds.extend(asset_seeds);
+
+        CreateV2CpiBuilder::new(self.mpl_core_program)
+            .asset(asset)
+            .name(metadata.ticket_name(ticket_number))
+            .collection(Some(self.collection))
+            .uri(metadata.ticket_uri(ticket_number))
+            .plugins(ticket_plugin)
+            .owner(Some(self.user))
+            .payer(self.user)
+            .authority(Some(self.bonding_curve.as_ref()))
+            .system_program(self.system_program)
+            .invoke_signed(&signer_seeds)?;
+
+        Ok(())
+    }
+}
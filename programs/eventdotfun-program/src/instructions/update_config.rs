@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, Config, ConfigUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, seeds = [Config::SEED.as_bytes()], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(constraint = user.key() == config.admin @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_config(
+        &mut self,
+        fee: u64,
+        fee_recipient: Pubkey,
+        referral_fee: u64,
+    ) -> Result<ConfigUpdated> {
+        Config::validate(fee, fee_recipient, referral_fee)?;
+
+        self.config.fee = fee;
+        self.config.fee_recipient = fee_recipient;
+        self.config.referral_fee = referral_fee;
+
+        ConfigUpdated::from_config(&self.config)
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BondingCurve, EventDetails, EventInfo, EventInfoUpdated};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateEventInfo<'info> {
+    #[account(
+        seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
+        bump = bonding_curve.bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        seeds = [EventInfo::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump = event_info.bump
+    )]
+    pub event_info: Account<'info, EventInfo>,
+
+    /// CHECK: only used to derive the bonding curve address
+    #[account(constraint = collection.key() == bonding_curve.collection)]
+    pub collection: UncheckedAccount<'info>,
+
+    #[account(constraint = user.key() == bonding_curve.creator @ ErrorCode::InvalidAuthority)]
+    pub user: Signer<'info>,
+}
+
+impl<'info> UpdateEventInfo<'info> {
+    pub fn update_event_info(&mut self, details: EventDetails) -> Result<EventInfoUpdated> {
+        let now = Clock::get()?.unix_timestamp as u64;
+        require!(
+            now < self.bonding_curve.start_at,
+            ErrorCode::EventInfoLocked
+        );
+
+        details.validate(
+            self.bonding_curve.max_ticket_to_sold,
+            self.bonding_curve.end_at,
+        )?;
+        self.bonding_curve.vesting.validate(details.event_end_at)?;
+        self.event_info.details = details.clone();
+
+        Ok(EventInfoUpdated {
+            bonding_curve: self.bonding_curve.key(),
+            details,
+            timestamp: now as i64,
+        })
+    }
+}
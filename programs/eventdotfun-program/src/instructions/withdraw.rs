@@ -3,20 +3,45 @@ use anchor_lang::{
     system_program::{transfer, Transfer},
 };
 
-use crate::{error::ErrorCode, BondingCurve};
+use crate::{
+    error::ErrorCode, BondingCurve, Config, EventInfo, RevenueSplit, SalesType, Withdrawn,
+};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(
         mut,
         seeds = [BondingCurve::SEED.as_bytes(), collection.key().as_ref()],
-        bump = bonding_curve.bump
+        bump = bonding_curve.bump,
+        constraint = !bonding_curve.paused @ ErrorCode::CurvePaused,
+        constraint = !bonding_curve.cancelled @ ErrorCode::EventCancelled
     )]
     pub bonding_curve: Account<'info, BondingCurve>,
 
     #[account(mut, seeds = [b"vault", bonding_curve.key().as_ref()], bump = bonding_curve.vault_bump)]
     pub vault: SystemAccount<'info>,
 
+    #[account(
+        seeds = [Config::SEED.as_bytes()],
+        bump = config.bump,
+        constraint = !config.paused @ ErrorCode::ProtocolPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [RevenueSplit::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump = revenue_split.bump
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    #[account(
+        seeds = [EventInfo::SEED.as_bytes(), bonding_curve.key().as_ref()],
+        bump = event_info.bump
+    )]
+    pub event_info: Account<'info, EventInfo>,
+
     /// CHECK:
     #[account(mut, constraint = collection.key() == bonding_curve.collection)]
     pub collection: UncheckedAccount<'info>,
@@ -28,22 +53,106 @@ pub struct Withdraw<'info> {
 }
 
 impl<'info> Withdraw<'info> {
-    pub fn withdraw(&mut self) -> Result<()> {
+    /// `recipients` are the split recipients' accounts, in the order stored on
+    /// `revenue_split`.
+    pub fn withdraw(&mut self, recipients: &[AccountInfo<'info>]) -> Result<Withdrawn> {
         let now = Clock::get()?.unix_timestamp as u64;
         let end = self.bonding_curve.end_at;
         let current_ticket_to_sold = self.bonding_curve.current_ticket_sold;
         let min_ticket_to_sold = self.bonding_curve.min_ticket_to_sold;
 
         require!(now >= end, ErrorCode::CurveStillOnProgress);
+        // refund-window holders are covered by the refund period instead, so
+        // whatever nobody refunded is revenue even below the threshold
         require!(
-            current_ticket_to_sold >= min_ticket_to_sold,
+            self.bonding_curve.sales_type == SalesType::RefundWindow
+                || current_ticket_to_sold >= min_ticket_to_sold,
             ErrorCode::CurveStillBelowThreshold
         );
 
+        let splits = self.revenue_split.recipients.clone();
+        require!(
+            recipients.len() == splits.len(),
+            ErrorCode::InvalidSplitRecipient
+        );
+
+        // 1. release whatever vested since the last withdrawal, leaving out
+        // shares already owed to split recipients
+        let total_withdrawn = self.bonding_curve.total_withdrawn;
+        let total_owed = self.revenue_split.total_owed()?;
+        let available = self
+            .vault
+            .lamports()
+            .saturating_sub(self.bonding_curve.required_vault_lamports(now)?)
+            .saturating_sub(total_owed);
+        let total = available
+            .checked_add(total_withdrawn)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        let event_end_at = self.event_info.details.event_end_at;
+        let vested = self
+            .bonding_curve
+            .vesting
+            .vested(total, event_end_at, now)?;
+        let amount = vested.saturating_sub(total_withdrawn);
+        require!(amount > 0, ErrorCode::NothingToClaim);
+
+        self.bonding_curve.total_withdrawn = total_withdrawn
+            .checked_add(amount)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        let mut creator_amount = amount;
+
+        // 2. pay split recipients. What would leave an unfunded recipient
+        // below the rent-exempt minimum is owed to them instead, until it can
+        // be delivered here or claimed with `claim_split`.
+        let rent = Rent::get()?;
+        for (index, (split, recipient)) in splits.iter().zip(recipients).enumerate() {
+            require_keys_eq!(
+                recipient.key(),
+                split.recipient,
+                ErrorCode::InvalidSplitRecipient
+            );
+
+            let share = RevenueSplit::share_of(split, amount)?;
+            creator_amount = creator_amount
+                .checked_sub(share)
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+            let due = self.revenue_split.owed[index]
+                .checked_add(share)
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+            let balance = recipient.lamports().saturating_add(due);
+            if due > 0 && rent.is_exempt(balance, recipient.data_len()) {
+                self.transfer_from_vault(recipient, due)?;
+                self.revenue_split.owed[index] = 0;
+            } else {
+                self.revenue_split.owed[index] = due;
+            }
+        }
+
+        // 3. pay creator
+        self.transfer_from_vault(&self.user.to_account_info(), creator_amount)?;
+        let vault_lamports = self
+            .vault
+            .lamports()
+            .saturating_sub(self.revenue_split.total_owed()?);
+        self.bonding_curve.ensure_solvent(vault_lamports, now)?;
+
+        Ok(Withdrawn {
+            bonding_curve: self.bonding_curve.key(),
+            creator: self.user.key(),
+            lamports: amount,
+            creator_lamports: creator_amount,
+            total_withdrawn: self.bonding_curve.total_withdrawn,
+            timestamp: now as i64,
+        })
+    }
+
+    fn transfer_from_vault(&self, to: &AccountInfo<'info>, lamports: u64) -> Result<()> {
         let cpi_program = self.system_program.to_account_info();
         let cpi_account = Transfer {
             from: self.vault.to_account_info(),
-            to: self.user.to_account_info(),
+            to: to.clone(),
         };
 
         let bonding_curve_key = self.bonding_curve.key();
@@ -55,8 +164,6 @@ impl<'info> Withdraw<'info> {
         let signer_seeds = &[&seeds[..]];
 
         let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_account, signer_seeds);
-        transfer(cpi_ctx, self.vault.lamports())?;
-
-        Ok(())
+        transfer(cpi_ctx, lamports)
     }
 }
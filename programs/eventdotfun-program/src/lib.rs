@@ -1,12 +1,16 @@
 #![allow(unexpected_cfgs)]
 pub mod constants;
+pub mod curve;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 
 use anchor_lang::prelude::*;
 
 pub use constants::*;
+pub use curve::CurveKind;
+pub use events::*;
 pub use instructions::*;
 pub use state::*;
 
@@ -16,54 +20,194 @@ declare_id!("8BRSpxYY3FhxvZZZBvFxFG5vwPWtLeeptHor7JBPeDtm");
 pub mod eventdotfun_program {
     use super::*;
 
-    pub fn initialize(ctx: Context<SetupConfig>, fee: u64, fee_recipient: Pubkey) -> Result<()> {
-        ctx.accounts
-            .initialize_config(fee, fee_recipient, &ctx.bumps)
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        fee: u64,
+        fee_recipient: Pubkey,
+        referral_fee: u64,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .initialize_config(fee, fee_recipient, referral_fee, &ctx.bumps)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        fee: u64,
+        fee_recipient: Pubkey,
+        referral_fee: u64,
+    ) -> Result<()> {
+        let event = ctx
+            .accounts
+            .update_config(fee, fee_recipient, referral_fee)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        let event = ctx.accounts.propose_admin(new_admin)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let event = ctx.accounts.accept_admin()?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
-    pub fn update_config(ctx: Context<SetupConfig>, fee: u64, fee_recipient: Pubkey) -> Result<()> {
-        ctx.accounts.update_config(fee, fee_recipient)
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        let event = ctx.accounts.set_paused(paused)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn set_curve_paused(ctx: Context<SetCurvePaused>, paused: bool) -> Result<()> {
+        let event = ctx.accounts.set_curve_paused(paused)?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_bonding_curve(
         ctx: Context<CreateBondingCurve>,
-        sales_type: u8,
+        sales_type: SalesType,
         start_at: u64,
         end_at: u64,
-        exponent: u8,
+        curve: CurveKind,
         initial_price: u64,
         last_price: u64,
         min_ticket_to_sold: u64,
         max_ticket_to_sold: u64,
         refund_window: u64,
+        refund_period: u64,
+        sell_policy: SellPolicy,
+        vesting: VestingSchedule,
+        revenue_splits: Vec<SplitRecipient>,
+        metadata: EventMetadata,
+        details: EventDetails,
     ) -> Result<()> {
-        ctx.accounts.create_bonding_curve(
+        let event = ctx.accounts.create_bonding_curve(
             sales_type,
             start_at,
             end_at,
-            exponent,
+            curve,
             initial_price,
             last_price,
             min_ticket_to_sold,
             max_ticket_to_sold,
             refund_window,
+            refund_period,
+            sell_policy,
+            vesting,
+            revenue_splits,
+            metadata,
+            details,
             &ctx.bumps,
-        )
+        )?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn cancel_event(ctx: Context<CancelEvent>) -> Result<()> {
+        let event = ctx.accounts.cancel_event()?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
-    pub fn buy(ctx: Context<Buy>) -> Result<()> {
-        ctx.accounts.buy()
+    pub fn update_event_info(ctx: Context<UpdateEventInfo>, details: EventDetails) -> Result<()> {
+        let event = ctx.accounts.update_event_info(details)?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
-    pub fn sell(ctx: Context<Sell>) -> Result<()> {
-        ctx.accounts.sell()
+    pub fn buy(ctx: Context<Buy>, max_lamports: u64) -> Result<()> {
+        let event = ctx.accounts.buy(max_lamports, &ctx.bumps)?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
-    pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
-        ctx.accounts.withdraw()
+    pub fn buy_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyMany<'info>>,
+        quantity: u64,
+        max_lamports: u64,
+    ) -> Result<()> {
+        let events = ctx
+            .accounts
+            .buy_many(quantity, max_lamports, ctx.remaining_accounts)?;
+        for event in events {
+            emit_cpi!(event);
+        }
+
+        Ok(())
+    }
+
+    pub fn sell(ctx: Context<Sell>, min_lamports: u64) -> Result<()> {
+        let event = ctx.accounts.sell(min_lamports)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn init_referral(ctx: Context<InitReferral>) -> Result<()> {
+        let event = ctx.accounts.init_referral(&ctx.bumps)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn claim_referral(ctx: Context<ClaimReferral>) -> Result<()> {
+        let event = ctx.accounts.claim_referral()?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn quote_buy(ctx: Context<Quote>, quantity: u64) -> Result<u64> {
+        ctx.accounts.quote_buy(quantity)
+    }
+
+    pub fn quote_sell(ctx: Context<QuoteSell>) -> Result<u64> {
+        ctx.accounts.quote_sell()
+    }
+
+    pub fn withdraw<'info>(ctx: Context<'_, '_, 'info, 'info, Withdraw<'info>>) -> Result<()> {
+        let event = ctx.accounts.withdraw(ctx.remaining_accounts)?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn claim_split(ctx: Context<ClaimSplit>) -> Result<()> {
+        let event = ctx.accounts.claim_split()?;
+        emit_cpi!(event);
+
+        Ok(())
+    }
+
+    pub fn close_bonding_curve(ctx: Context<CloseBondingCurve>) -> Result<()> {
+        let event = ctx.accounts.close_bonding_curve()?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 
     pub fn refund(ctx: Context<Refund>) -> Result<()> {
-        ctx.accounts.refund()
+        let event = ctx.accounts.refund()?;
+        emit_cpi!(event);
+
+        Ok(())
     }
 }
@@ -1,33 +1,297 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    curve::{self, CurveKind},
+    error::ErrorCode,
+    EventMetadata, TicketReceipt, VestingSchedule,
+};
+
+/// When ticket holders can get their money back.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SalesType {
+    /// Refunds open if the sale ends below `min_ticket_to_sold`.
+    Threshold,
+    /// Refunds open `refund_window` seconds after the sale ends and stay
+    /// open for `refund_period` seconds.
+    RefundWindow,
+}
+
+/// How a ticket sold back before the threshold is priced.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SellPolicy {
+    /// Only the most recently bought outstanding ticket can go back, at its
+    /// own price.
+    LastIn,
+    /// Any ticket can go back for exactly what was paid for it.
+    PaidPrice,
+    /// Any ticket can go back at the current top-of-curve price.
+    CurrentCurve,
+}
+
 #[account]
 pub struct BondingCurve {
     pub creator: Pubkey,
-    pub sales_type: u8,
+    pub sales_type: SalesType,
     pub start_at: u64,
     pub end_at: u64,
 
     pub collection: Pubkey,
+    pub metadata: EventMetadata,
 
-    pub exponent: u8,
+    pub curve: CurveKind,
     pub initial_price: u64,
     pub last_price: u64,
-    pub multiplier: u64,
     pub max_ticket_to_sold: u64,
     pub current_ticket_sold: u64,
     pub min_ticket_to_sold: u64,
+    pub total_minted: u64,
 
     pub total_sol: u64,
     pub total_refund: u64,
+    pub total_withdrawn: u64,
+    /// Sum of the prices paid for tickets not yet sold back or refunded.
+    pub total_reserved: u64,
 
     pub refund_window: u64,
+    pub refund_period: u64,
+    pub sell_policy: SellPolicy,
+    pub vesting: VestingSchedule,
+
+    /// Set by the admin: halts the whole curve, refunds included.
+    pub paused: bool,
+    /// Set by the creator: halts `buy` and `buy_many` only.
+    pub sales_paused: bool,
+    pub paused_at: u64,
+    /// Seconds the refund period was held open by admin pauses inside it.
+    pub refund_extension: u64,
+    pub cancelled: bool,
 
     pub bump: u8,
     pub vault_bump: u8,
 }
 
 impl BondingCurve {
-    pub const INIT_SPACE: usize = 8 + 32 + 1 + 8 + 8 + 32 + 1 + (8 * 9) + 1 + 1;
+    pub const INIT_SPACE: usize = 8
+        + 32
+        + 1
+        + 8
+        + 8
+        + 32
+        + EventMetadata::INIT_SPACE
+        + CurveKind::INIT_SPACE
+        + VestingSchedule::INIT_SPACE
+        + (8 * 14)
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1
+        + 1;
 
     pub const SEED: &'static str = "bonding_curve";
+
+    pub const TICKET_SEED: &'static str = "ticket";
+
+    pub fn price_at(&self, ticket_number: u64) -> Result<u64> {
+        curve::price_at(
+            &self.curve,
+            self.initial_price,
+            self.last_price,
+            self.min_ticket_to_sold,
+            ticket_number,
+        )
+    }
+
+    /// Books a newly sold ticket paid `lamports`.
+    pub fn record_buy(&mut self, lamports: u64) -> Result<()> {
+        self.current_ticket_sold = self
+            .current_ticket_sold
+            .checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.total_minted = self
+            .total_minted
+            .checked_add(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.total_sol = self
+            .total_sol
+            .checked_add(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.total_reserved = self
+            .total_reserved
+            .checked_add(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        Ok(())
+    }
+
+    /// Books a ticket bought for `price` and sold back for `lamports`.
+    pub fn record_sell(&mut self, lamports: u64, price: u64) -> Result<()> {
+        self.current_ticket_sold = self
+            .current_ticket_sold
+            .checked_sub(1)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.total_sol = self
+            .total_sol
+            .checked_sub(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.total_reserved = self
+            .total_reserved
+            .checked_sub(price)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        Ok(())
+    }
+
+    /// Books a ticket refunded for `lamports`.
+    pub fn record_refund(&mut self, lamports: u64) -> Result<()> {
+        self.total_refund = self
+            .total_refund
+            .checked_add(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        self.total_reserved = self
+            .total_reserved
+            .checked_sub(lamports)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        Ok(())
+    }
+
+    /// `[opens_at, closes_at)` in which a refund-window sale refunds at
+    /// `now`. Time the admin kept the curve paused inside the period doesn't
+    /// count against it.
+    pub fn refund_period_bounds(&self, now: u64) -> Result<(u64, u64)> {
+        let (opens_at, closes_at) = self.unpaused_refund_period_bounds()?;
+        let closes_at = closes_at
+            .checked_add(self.refund_pause_overlap(opens_at, closes_at, now))
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        Ok((opens_at, closes_at))
+    }
+
+    /// Refund period extended by finished pauses only.
+    fn unpaused_refund_period_bounds(&self) -> Result<(u64, u64)> {
+        let opens_at = self
+            .end_at
+            .checked_add(self.refund_window)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+        let closes_at = opens_at
+            .checked_add(self.refund_period)
+            .and_then(|closes_at| closes_at.checked_add(self.refund_extension))
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        Ok((opens_at, closes_at))
+    }
+
+    /// Part of the ongoing admin pause that falls inside the refund period.
+    fn refund_pause_overlap(&self, opens_at: u64, closes_at: u64, now: u64) -> u64 {
+        if !self.paused || self.paused_at >= closes_at {
+            return 0;
+        }
+
+        now.saturating_sub(self.paused_at.max(opens_at))
+    }
+
+    /// Admin pause. Pausing inside the refund period holds it open until the
+    /// curve is unpaused.
+    pub fn set_paused(&mut self, paused: bool, now: u64) -> Result<()> {
+        if paused == self.paused {
+            return Ok(());
+        }
+
+        if paused {
+            self.paused_at = now;
+        } else {
+            let (opens_at, closes_at) = self.unpaused_refund_period_bounds()?;
+            self.refund_extension = self
+                .refund_extension
+                .checked_add(self.refund_pause_overlap(opens_at, closes_at, now))
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+            self.paused_at = 0;
+        }
+        self.paused = paused;
+
+        Ok(())
+    }
+
+    /// Whether ticket holders can still get their money back at `now`.
+    pub fn is_refundable(&self, now: u64) -> Result<bool> {
+        if self.cancelled {
+            return Ok(true);
+        }
+
+        match self.sales_type {
+            SalesType::Threshold => Ok(self.current_ticket_sold < self.min_ticket_to_sold),
+            SalesType::RefundWindow => {
+                let (_, closes_at) = self.refund_period_bounds(now)?;
+                Ok(now < closes_at)
+            }
+        }
+    }
+
+    /// Lamports the vault must hold at `now`: its rent-exempt minimum plus
+    /// every ticket price that can still be claimed back.
+    pub fn required_vault_lamports(&self, now: u64) -> Result<u64> {
+        let reserved = if self.is_refundable(now)? {
+            self.total_reserved
+        } else {
+            0
+        };
+
+        Rent::get()?
+            .minimum_balance(0)
+            .checked_add(reserved)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    pub fn ensure_solvent(&self, vault_lamports: u64, now: u64) -> Result<()> {
+        require!(
+            vault_lamports >= self.required_vault_lamports(now)?,
+            ErrorCode::VaultInsolvent
+        );
+
+        Ok(())
+    }
+
+    /// Tickets can only go back while the sale runs and is below threshold.
+    pub fn ensure_sell_open(&self, now: u64) -> Result<()> {
+        require!(now <= self.end_at, ErrorCode::CurveEnded);
+        require!(
+            self.current_ticket_sold < self.min_ticket_to_sold,
+            ErrorCode::CurveReachesThreshold
+        );
+
+        Ok(())
+    }
+
+    /// What selling back the ticket behind `receipt` pays. Never more than
+    /// the ticket's own purchase price, so the vault always covers the
+    /// tickets still outstanding.
+    pub fn sell_price(&self, receipt: &TicketReceipt) -> Result<u64> {
+        match self.sell_policy {
+            SellPolicy::LastIn => {
+                require!(
+                    receipt.curve_index == self.current_ticket_sold,
+                    ErrorCode::NotLastTicket
+                );
+                Ok(receipt.price)
+            }
+            SellPolicy::PaidPrice => Ok(receipt.price),
+            SellPolicy::CurrentCurve => {
+                Ok(self.price_at(self.current_ticket_sold)?.min(receipt.price))
+            }
+        }
+    }
+
+    /// Sum of the prices of `quantity` tickets starting at `first_ticket`.
+    pub fn price_for(&self, first_ticket: u64, quantity: u64) -> Result<u64> {
+        let end = first_ticket
+            .checked_add(quantity)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        (first_ticket..end).try_fold(0_u64, |total, ticket_number| {
+            total
+                .checked_add(self.price_at(ticket_number)?)
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))
+        })
+    }
 }
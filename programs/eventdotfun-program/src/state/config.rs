@@ -1,14 +1,50 @@
 use anchor_lang::prelude::*;
 
+use crate::{error::ErrorCode, BASIS_POINTS, MAX_FEE_BPS};
+
 #[account]
 pub struct Config {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
     pub fee: u64,
     pub fee_recipient: Pubkey,
+    pub referral_fee: u64,
+    pub paused: bool,
     pub bump: u8,
 }
 
 impl Config {
-    pub const INIT_SPACE: usize = 8 + 8 + 32 + 1;
+    pub const INIT_SPACE: usize = 8 + 32 + (1 + 32) + 8 + 32 + 8 + 1 + 1;
 
     pub const SEED: &'static str = "config";
+
+    pub fn validate(fee: u64, fee_recipient: Pubkey, referral_fee: u64) -> Result<()> {
+        require!(fee <= MAX_FEE_BPS, ErrorCode::InvalidFee);
+        require!(
+            fee_recipient != Pubkey::default(),
+            ErrorCode::InvalidFeeRecipient
+        );
+        require!(referral_fee <= fee, ErrorCode::InvalidReferralFee);
+
+        Ok(())
+    }
+
+    /// Protocol fee charged on top of `lamports`.
+    pub fn fee_for(&self, lamports: u64) -> Result<u64> {
+        Self::bps_of(lamports, self.fee)
+    }
+
+    /// Part of the protocol fee credited to a referrer.
+    pub fn referral_fee_for(&self, lamports: u64) -> Result<u64> {
+        Self::bps_of(lamports, self.referral_fee)
+    }
+
+    fn bps_of(lamports: u64, bps: u64) -> Result<u64> {
+        let amount = (lamports as u128)
+            .checked_mul(bps as u128)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?
+            / BASIS_POINTS as u128;
+
+        Ok(amount as u64)
+    }
 }
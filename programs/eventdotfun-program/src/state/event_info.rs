@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventCategory {
+    Meetup,
+    Conference,
+    Concert,
+    Festival,
+    Sports,
+    Other,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EventDetails {
+    pub venue: String,
+    pub event_start_at: u64,
+    pub event_end_at: u64,
+    pub capacity: u64,
+    pub category: EventCategory,
+    /// Hash of the off-chain event metadata document.
+    pub metadata_hash: [u8; 32],
+}
+
+impl EventDetails {
+    pub const MAX_VENUE_LEN: usize = 64;
+
+    pub const INIT_SPACE: usize = (4 + Self::MAX_VENUE_LEN) + 8 + 8 + 8 + 1 + 32;
+
+    /// The event can't start before the sale that funds it has ended.
+    pub fn validate(&self, max_ticket_to_sold: u64, sale_end_at: u64) -> Result<()> {
+        require!(
+            !self.venue.is_empty() && self.venue.len() <= Self::MAX_VENUE_LEN,
+            ErrorCode::InvalidEventInfo
+        );
+        require!(
+            self.event_start_at >= sale_end_at && self.event_end_at >= self.event_start_at,
+            ErrorCode::InvalidEventInfo
+        );
+        require!(
+            self.capacity >= max_ticket_to_sold,
+            ErrorCode::InvalidEventInfo
+        );
+
+        Ok(())
+    }
+}
+
+/// What the event is, kept next to its bonding curve.
+#[account]
+pub struct EventInfo {
+    pub bonding_curve: Pubkey,
+    pub details: EventDetails,
+    pub bump: u8,
+}
+
+impl EventInfo {
+    pub const INIT_SPACE: usize = 8 + 32 + EventDetails::INIT_SPACE + 1;
+
+    pub const SEED: &'static str = "event_info";
+}
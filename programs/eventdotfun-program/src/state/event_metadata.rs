@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Wallet-facing metadata for an event's collection and tickets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EventMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    /// Ticket URI, with `{n}` replaced by the ticket number.
+    pub ticket_uri: String,
+}
+
+impl EventMetadata {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_SYMBOL_LEN: usize = 10;
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const INIT_SPACE: usize = (4 + Self::MAX_NAME_LEN)
+        + (4 + Self::MAX_SYMBOL_LEN)
+        + (4 + Self::MAX_URI_LEN)
+        + (4 + Self::MAX_URI_LEN);
+
+    pub const TICKET_NUMBER_PLACEHOLDER: &'static str = "{n}";
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            !self.name.is_empty() && self.name.len() <= Self::MAX_NAME_LEN,
+            ErrorCode::InvalidMetadata
+        );
+        require!(
+            self.symbol.len() <= Self::MAX_SYMBOL_LEN,
+            ErrorCode::InvalidMetadata
+        );
+        require!(
+            !self.uri.is_empty() && self.uri.len() <= Self::MAX_URI_LEN,
+            ErrorCode::InvalidMetadata
+        );
+        require!(
+            !self.ticket_uri.is_empty() && self.ticket_uri.len() <= Self::MAX_URI_LEN,
+            ErrorCode::InvalidMetadata
+        );
+
+        Ok(())
+    }
+
+    pub fn ticket_name(&self, ticket_number: u64) -> String {
+        format!("{} #{}", self.name, ticket_number)
+    }
+
+    pub fn ticket_uri(&self, ticket_number: u64) -> String {
+        self.ticket_uri
+            .replace(Self::TICKET_NUMBER_PLACEHOLDER, &ticket_number.to_string())
+    }
+}
@@ -3,3 +3,21 @@ pub use bonding_curve::*;
 
 pub mod config;
 pub use config::*;
+
+pub mod event_info;
+pub use event_info::*;
+
+pub mod event_metadata;
+pub use event_metadata::*;
+
+pub mod referral;
+pub use referral::*;
+
+pub mod revenue_split;
+pub use revenue_split::*;
+
+pub mod ticket_receipt;
+pub use ticket_receipt::*;
+
+pub mod vesting;
+pub use vesting::*;
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Referral rewards credited to a promoter, held as lamports on this PDA
+/// until claimed.
+#[account]
+pub struct Referral {
+    pub referrer: Pubkey,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 8 + 1;
+
+    pub const SEED: &'static str = "referral";
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::ErrorCode, BASIS_POINTS};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SplitRecipient {
+    pub recipient: Pubkey,
+    pub share_bps: u16,
+}
+
+impl SplitRecipient {
+    pub const INIT_SPACE: usize = 32 + 2;
+}
+
+/// Co-host, venue or artist shares of the primary sales, escrowed in the
+/// vault and paid out by `withdraw`.
+#[account]
+pub struct RevenueSplit {
+    pub bonding_curve: Pubkey,
+    pub recipients: Vec<SplitRecipient>,
+    /// Shares not delivered yet, by recipient index. They stay in the vault
+    /// until the recipient can receive them.
+    pub owed: Vec<u64>,
+    pub bump: u8,
+}
+
+impl RevenueSplit {
+    pub const MAX_RECIPIENTS: usize = 5;
+
+    pub const INIT_SPACE: usize = 8
+        + 32
+        + (4 + SplitRecipient::INIT_SPACE * Self::MAX_RECIPIENTS)
+        + (4 + 8 * Self::MAX_RECIPIENTS)
+        + 1;
+
+    pub const SEED: &'static str = "revenue_split";
+
+    pub fn validate(recipients: &[SplitRecipient]) -> Result<()> {
+        require!(
+            recipients.len() <= Self::MAX_RECIPIENTS,
+            ErrorCode::InvalidRevenueSplit
+        );
+
+        let mut total_bps = 0_u64;
+        for (index, split) in recipients.iter().enumerate() {
+            require!(split.share_bps > 0, ErrorCode::InvalidRevenueSplit);
+            require!(
+                recipients[..index]
+                    .iter()
+                    .all(|other| other.recipient != split.recipient),
+                ErrorCode::InvalidRevenueSplit
+            );
+            total_bps += split.share_bps as u64;
+        }
+        require!(total_bps <= BASIS_POINTS, ErrorCode::InvalidRevenueSplit);
+
+        Ok(())
+    }
+
+    pub fn share_of(split: &SplitRecipient, lamports: u64) -> Result<u64> {
+        let share = (lamports as u128)
+            .checked_mul(split.share_bps as u128)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?
+            / BASIS_POINTS as u128;
+
+        Ok(share as u64)
+    }
+
+    pub fn total_owed(&self) -> Result<u64> {
+        self.owed.iter().try_fold(0_u64, |total, owed| {
+            total
+                .checked_add(*owed)
+                .ok_or(error!(ErrorCode::ArithmeticOverflow))
+        })
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// What was actually paid for a ticket, keyed by its asset. Closed when the
+/// ticket is sold back or refunded.
+#[account]
+pub struct TicketReceipt {
+    pub bonding_curve: Pubkey,
+    pub asset: Pubkey,
+    pub buyer: Pubkey,
+    pub ticket_number: u64,
+    /// Position on the curve the ticket was priced at.
+    pub curve_index: u64,
+    pub price: u64,
+    pub fee: u64,
+    pub purchased_at: i64,
+    pub bump: u8,
+}
+
+impl TicketReceipt {
+    pub const INIT_SPACE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    pub const SEED: &'static str = "receipt";
+}
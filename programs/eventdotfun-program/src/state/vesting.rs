@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+
+/// Release schedule for the creator's proceeds: nothing until `cliff`
+/// seconds after the event ends, then linear over `duration` seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VestingSchedule {
+    pub cliff: u64,
+    pub duration: u64,
+}
+
+impl VestingSchedule {
+    pub const INIT_SPACE: usize = 8 + 8;
+
+    pub fn validate(&self, event_end_at: u64) -> Result<()> {
+        require!(
+            self.checked_ends_at(event_end_at).is_some(),
+            ErrorCode::InvalidVestingSchedule
+        );
+
+        Ok(())
+    }
+
+    /// When everything has been released for an event ending at
+    /// `event_end_at`.
+    pub fn ends_at(&self, event_end_at: u64) -> Result<u64> {
+        self.checked_ends_at(event_end_at)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))
+    }
+
+    /// Portion of `total` released at `now` for an event ending at
+    /// `event_end_at`.
+    pub fn vested(&self, total: u64, event_end_at: u64, now: u64) -> Result<u64> {
+        let release_at = event_end_at
+            .checked_add(self.cliff)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?;
+
+        if now < release_at {
+            return Ok(0);
+        }
+
+        let elapsed = now - release_at;
+        if elapsed >= self.duration {
+            return Ok(total);
+        }
+
+        let vested = (total as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(error!(ErrorCode::ArithmeticOverflow))?
+            / self.duration as u128;
+
+        Ok(vested as u64)
+    }
+
+    fn checked_ends_at(&self, event_end_at: u64) -> Option<u64> {
+        event_end_at
+            .checked_add(self.cliff)?
+            .checked_add(self.duration)
+    }
+}
@@ -1,9 +1,16 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { EventdotfunProgram } from "../target/types/eventdotfun_program";
-import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  Transaction,
+} from "@solana/web3.js";
 import { SYSTEM_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/native/system";
 import { BN } from "bn.js";
+import { expect } from "chai";
 
 const deployerKeypair = Keypair.fromSecretKey(
   new Uint8Array(require("../keys/turbin3.json")),
@@ -21,36 +28,119 @@ describe("eventdotfun-program", () => {
 
   let globalCollection;
   let globalAsset;
+  let quotedPrice: BN;
+
+  // slippage bounds, generous enough for every step of this curve
+  const maxLamports = new BN(5 * LAMPORTS_PER_SOL);
+  const minLamports = new BN(0);
+
+  const [config] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId,
+  );
+
+  const [programData] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"),
+  );
+
+  const receiptFor = (asset: PublicKey) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("receipt"), asset.toBuffer()],
+      program.programId,
+    )[0];
 
   it("Config State Initialized!", async () => {
     const fee = new anchor.BN(1000); // 10%
     const feeRecipient = feeRecipientKeypair.publicKey;
 
-    const tx = await program.methods.initialize(fee, feeRecipient).rpc();
+    const referralFee = new anchor.BN(500); // 5%, taken out of the fee
+
+    const tx = await program.methods
+      .initialize(fee, feeRecipient, referralFee)
+      .accounts({
+        // @ts-ignore
+        config,
+        program: program.programId,
+        programData,
+        user: deployerKeypair.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .rpc();
     console.log("Your transaction signature", tx);
   });
 
+  it("Config State Cannot Be Re-initialized!", async () => {
+    const fee = new anchor.BN(0);
+    const feeRecipient = feeRecipientKeypair.publicKey;
+    const referralFee = new anchor.BN(0);
+
+    let failed = false;
+    try {
+      await program.methods
+        .initialize(fee, feeRecipient, referralFee)
+        .accounts({
+          // @ts-ignore
+          config,
+          program: program.programId,
+          programData,
+          user: deployerKeypair.publicKey,
+          systemProgram: SYSTEM_PROGRAM_ID,
+        })
+        .rpc();
+    } catch (_) {
+      failed = true;
+    }
+    expect(failed).to.be.true;
+  });
+
   it("Config State Updated!", async () => {
     const fee = new anchor.BN(1000); // 10%
     const feeRecipient = feeRecipientKeypair.publicKey;
 
-    const tx = await program.methods.updateConfig(fee, feeRecipient).rpc();
+    const referralFee = new anchor.BN(500); // 5%, taken out of the fee
+
+    const tx = await program.methods
+      .updateConfig(fee, feeRecipient, referralFee)
+      .rpc();
     console.log("Your transaction signature", tx);
   });
 
   it("Bonding Curve Created!", async () => {
     const now = Math.floor(Date.now() / 1000);
 
-    const salesType = 1;
+    const salesType = { threshold: {} };
     const startAt = new anchor.BN(now + 1);
     const endAt = new anchor.BN(now + 10);
-    const exponent = 2;
+    const curve = { polynomial: { 0: { exponent: 2 } } };
     const initialPrice = new anchor.BN(0.001 * LAMPORTS_PER_SOL);
     const lastPrice = new anchor.BN(1 * LAMPORTS_PER_SOL);
     // const minTicketToSold = new BN(80);
     const minTicketToSold = new BN(3);
     const maxTicketToSold = new anchor.BN(100);
-    const refundWindow = new BN(1746320400);
+    const refundWindow = new BN(0); // only used by refund-window sales
+    const refundPeriod = new BN(0);
+    const sellPolicy = { lastIn: {} };
+    // everything vests as soon as the event ends
+    const vesting = { cliff: new BN(0), duration: new BN(0) };
+    const revenueSplits = [
+      { recipient: feeRecipientKeypair.publicKey, shareBps: 500 }, // 5%
+    ];
+    const metadata = {
+      name: "EVENTDOTFUN Meetup",
+      symbol: "EDF",
+      uri: "https://devnet.irys.xyz/2x17GpZTmXPKGGiUKuaQA5b9jg3tQuG7VquatBLdkFB2",
+      ticketUri:
+        "https://devnet.irys.xyz/2x17GpZTmXPKGGiUKuaQA5b9jg3tQuG7VquatBLdkFB2?ticket={n}",
+    };
+    const details = {
+      venue: "Turbin3 HQ",
+      eventStartAt: new BN(now + 10),
+      eventEndAt: new BN(now + 10),
+      capacity: new BN(100),
+      category: { meetup: {} },
+      metadataHash: Array(32).fill(0),
+    };
 
     const collection = Keypair.generate();
 
@@ -66,22 +156,40 @@ describe("eventdotfun-program", () => {
       program.programId,
     );
 
+    const [revenueSplit] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue_split"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const [eventInfo] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event_info"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
     const tx = await program.methods
       .createBondingCurve(
         salesType,
         startAt,
         endAt,
-        exponent,
+        curve,
         initialPrice,
         lastPrice,
         minTicketToSold,
         maxTicketToSold,
         refundWindow,
+        refundPeriod,
+        sellPolicy,
+        vesting,
+        revenueSplits,
+        metadata,
+        details,
       )
       .accounts({
         // @ts-ignore
         bondingCurve,
         vault,
+        revenueSplit,
+        eventInfo,
         collection: collection.publicKey,
         user: deployerKeypair.publicKey,
         systemProgram: SYSTEM_PROGRAM_ID,
@@ -96,6 +204,24 @@ describe("eventdotfun-program", () => {
     await sleep(5000);
   });
 
+  it("Quote!", async () => {
+    const [bondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), globalCollection.toBuffer()],
+      program.programId,
+    );
+
+    quotedPrice = await program.methods
+      .quoteBuy(new BN(1))
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        collection: globalCollection,
+        config,
+      })
+      .view();
+    console.log("Quoted price", quotedPrice.toString());
+  });
+
   it("1st Buy!", async () => {
     const asset = Keypair.generate();
 
@@ -112,13 +238,17 @@ describe("eventdotfun-program", () => {
     );
 
     const tx = await program.methods
-      .buy()
+      .buy(maxLamports)
       .accounts({
         // @ts-ignore
         bondingCurve,
         vault,
+        config,
+        feeRecipient: feeRecipientKeypair.publicKey,
+        referral: null,
         collection: globalCollection,
         asset: asset.publicKey,
+        receipt: receiptFor(asset.publicKey),
         user: deployerKeypair.publicKey,
         systemProgram: SYSTEM_PROGRAM_ID,
         mplCoreProgram: new PublicKey(
@@ -130,6 +260,18 @@ describe("eventdotfun-program", () => {
         commitment: "confirmed",
       });
     console.log("Your transaction signature", tx);
+
+    const receipt = await program.account.ticketReceipt.fetch(
+      receiptFor(asset.publicKey),
+    );
+    expect(receipt.buyer.toBase58()).to.equal(
+      deployerKeypair.publicKey.toBase58(),
+    );
+
+    // the quote is the fee-inclusive total the next buy charges
+    expect(receipt.price.add(receipt.fee).toString()).to.equal(
+      quotedPrice.toString(),
+    );
   });
 
   it("Sell!", async () => {
@@ -144,13 +286,15 @@ describe("eventdotfun-program", () => {
     );
 
     const tx = await program.methods
-      .sell()
+      .sell(minLamports)
       .accounts({
         // @ts-ignore
         bondingCurve,
         vault,
+        config,
         collection: globalCollection,
         asset: globalAsset,
+        receipt: receiptFor(globalAsset),
         user: deployerKeypair.publicKey,
         systemProgram: SYSTEM_PROGRAM_ID,
         mplCoreProgram: new PublicKey(
@@ -179,13 +323,17 @@ describe("eventdotfun-program", () => {
     );
 
     const tx = await program.methods
-      .buy()
+      .buy(maxLamports)
       .accounts({
         // @ts-ignore
         bondingCurve,
         vault,
+        config,
+        feeRecipient: feeRecipientKeypair.publicKey,
+        referral: null,
         collection: globalCollection,
         asset: asset.publicKey,
+        receipt: receiptFor(asset.publicKey),
         user: deployerKeypair.publicKey,
         systemProgram: SYSTEM_PROGRAM_ID,
         mplCoreProgram: new PublicKey(
@@ -197,6 +345,13 @@ describe("eventdotfun-program", () => {
         commitment: "confirmed",
       });
     console.log("Your transaction signature", tx);
+
+    // ticket #1 was sold back, so this is ticket #2 priced at curve slot 1
+    const receipt = await program.account.ticketReceipt.fetch(
+      receiptFor(asset.publicKey),
+    );
+    expect(receipt.ticketNumber.toNumber()).to.equal(2);
+    expect(receipt.curveIndex.toNumber()).to.equal(1);
   });
 
   it("3rd Buy!", async () => {
@@ -215,13 +370,17 @@ describe("eventdotfun-program", () => {
     );
 
     const tx = await program.methods
-      .buy()
+      .buy(maxLamports)
       .accounts({
         // @ts-ignore
         bondingCurve,
         vault,
+        config,
+        feeRecipient: feeRecipientKeypair.publicKey,
+        referral: null,
         collection: globalCollection,
         asset: asset.publicKey,
+        receipt: receiptFor(asset.publicKey),
         user: deployerKeypair.publicKey,
         systemProgram: SYSTEM_PROGRAM_ID,
         mplCoreProgram: new PublicKey(
@@ -251,13 +410,17 @@ describe("eventdotfun-program", () => {
     );
 
     const tx = await program.methods
-      .buy()
+      .buy(maxLamports)
       .accounts({
         // @ts-ignore
         bondingCurve,
         vault,
+        config,
+        feeRecipient: feeRecipientKeypair.publicKey,
+        referral: null,
         collection: globalCollection,
         asset: asset.publicKey,
+        receipt: receiptFor(asset.publicKey),
         user: deployerKeypair.publicKey,
         systemProgram: SYSTEM_PROGRAM_ID,
         mplCoreProgram: new PublicKey(
@@ -271,6 +434,84 @@ describe("eventdotfun-program", () => {
     console.log("Your transaction signature", tx);
   });
 
+  it("Buy Many!", async () => {
+    const quantity = 2;
+
+    const [bondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), globalCollection.toBuffer()],
+      program.programId,
+    );
+
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const { totalMinted } = await program.account.bondingCurve.fetch(
+      bondingCurve,
+    );
+
+    // each ticket asset is followed by its receipt
+    const assets = Array.from({ length: quantity }, (_, i) => {
+      const [asset] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("ticket"),
+          bondingCurve.toBuffer(),
+          totalMinted.addn(i).toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId,
+      );
+      return [
+        { pubkey: asset, isWritable: true, isSigner: false },
+        { pubkey: receiptFor(asset), isWritable: true, isSigner: false },
+      ];
+    }).flat();
+
+    // a pre-funded receipt address must not block the purchase
+    await program.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: deployerKeypair.publicKey,
+          toPubkey: assets[1].pubkey,
+          lamports: 1_000_000,
+        }),
+      ),
+    );
+
+    const tx = await program.methods
+      .buyMany(new BN(quantity), maxLamports)
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        config,
+        feeRecipient: feeRecipientKeypair.publicKey,
+        referral: null,
+        collection: globalCollection,
+        user: deployerKeypair.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+        mplCoreProgram: new PublicKey(
+          "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d",
+        ),
+      })
+      .remainingAccounts(assets)
+      .rpc({
+        commitment: "confirmed",
+      });
+    console.log("Your transaction signature", tx);
+
+    // the receipts split the fee charged on the whole batch
+    const receipts = await Promise.all(
+      assets
+        .filter((_, i) => i % 2 === 1)
+        .map(({ pubkey }) => program.account.ticketReceipt.fetch(pubkey)),
+    );
+    const price = receipts.reduce((total, r) => total.add(r.price), new BN(0));
+    const fee = receipts.reduce((total, r) => total.add(r.fee), new BN(0));
+    const { fee: feeBps } = await program.account.config.fetch(config);
+    expect(fee.toString()).to.equal(price.mul(feeBps).divn(10_000).toString());
+  });
+
   it("withdraw!", async () => {
     await sleep(10000);
 
@@ -284,18 +525,806 @@ describe("eventdotfun-program", () => {
       program.programId,
     );
 
+    const [revenueSplit] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue_split"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const [eventInfo] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event_info"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
     const tx = await program.methods
       .withdraw()
       .accounts({
         // @ts-ignore
         bondingCurve,
         vault,
+        config,
+        revenueSplit,
+        eventInfo,
         collection: globalCollection,
         user: deployerKeypair.publicKey,
         systemProgram: SYSTEM_PROGRAM_ID,
       })
+      .remainingAccounts([
+        {
+          pubkey: feeRecipientKeypair.publicKey,
+          isWritable: true,
+          isSigner: false,
+        },
+      ])
       .rpc();
     console.log("Your transaction signature", tx);
+
+    // the vault keeps its rent-exempt minimum
+    const connection = program.provider.connection;
+    expect(await connection.getBalance(vault)).to.equal(
+      await connection.getMinimumBalanceForRentExemption(0),
+    );
+  });
+
+  const mplCoreProgram = new PublicKey(
+    "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d",
+  );
+
+  // standalone curve selling from `now + startSeconds` to `now + saleSeconds`,
+  // with the event itself held right after the sale
+  const createCurve = async (
+    salesType,
+    saleSeconds: number,
+    refundPeriod = 0,
+    {
+      creator = deployerKeypair,
+      revenueSplits = [],
+      vesting = { cliff: new BN(0), duration: new BN(0) },
+      sellPolicy = { paidPrice: {} },
+      startSeconds = 1,
+    } = {},
+  ) => {
+    const now = Math.floor(Date.now() / 1000);
+
+    const collection = Keypair.generate();
+
+    const [bondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), collection.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const [revenueSplit] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue_split"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const [eventInfo] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event_info"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .createBondingCurve(
+        salesType,
+        new BN(now + startSeconds),
+        new BN(now + saleSeconds),
+        { linear: { 0: {} } },
+        new BN(0.001 * LAMPORTS_PER_SOL),
+        new BN(0.01 * LAMPORTS_PER_SOL),
+        new BN(3),
+        new BN(100),
+        new BN(0),
+        new BN(refundPeriod),
+        sellPolicy,
+        vesting,
+        revenueSplits,
+        {
+          name: "EVENTDOTFUN Refund",
+          symbol: "EDF",
+          uri: "https://devnet.irys.xyz/2x17GpZTmXPKGGiUKuaQA5b9jg3tQuG7VquatBLdkFB2",
+          ticketUri:
+            "https://devnet.irys.xyz/2x17GpZTmXPKGGiUKuaQA5b9jg3tQuG7VquatBLdkFB2?ticket={n}",
+        },
+        {
+          venue: "Turbin3 HQ",
+          eventStartAt: new BN(now + saleSeconds),
+          eventEndAt: new BN(now + saleSeconds),
+          capacity: new BN(100),
+          category: { meetup: {} },
+          metadataHash: Array(32).fill(0),
+        },
+      )
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        revenueSplit,
+        eventInfo,
+        collection: collection.publicKey,
+        user: creator.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+        mplCoreProgram,
+      })
+      .signers([collection, creator])
+      .rpc({ commitment: "confirmed" });
+
+    return {
+      collection: collection.publicKey,
+      bondingCurve,
+      vault,
+      revenueSplit,
+      eventInfo,
+    };
+  };
+
+  const buyTicket = async (
+    { collection, bondingCurve, vault },
+    {
+      buyer = deployerKeypair,
+      referral = null,
+      maxLamports: max = maxLamports,
+    } = {},
+  ) => {
+    const asset = Keypair.generate();
+    const receipt = receiptFor(asset.publicKey);
+
+    await program.methods
+      .buy(max)
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        config,
+        feeRecipient: feeRecipientKeypair.publicKey,
+        referral,
+        collection,
+        asset: asset.publicKey,
+        receipt,
+        user: buyer.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+        mplCoreProgram,
+      })
+      .signers([asset, buyer])
+      .rpc({ commitment: "confirmed" });
+
+    return { asset: asset.publicKey, receipt };
+  };
+
+  const refundTicket = (
+    { collection, bondingCurve, vault },
+    { asset, receipt },
+    user = deployerKeypair,
+  ) =>
+    program.methods
+      .refund()
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        collection,
+        asset,
+        receipt,
+        user: user.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+        mplCoreProgram,
+      })
+      .signers([user])
+      .rpc({ commitment: "confirmed" });
+
+  const sellTicket = (
+    { collection, bondingCurve, vault },
+    { asset, receipt },
+    user = deployerKeypair,
+    min = minLamports,
+  ) =>
+    program.methods
+      .sell(min)
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        config,
+        collection,
+        asset,
+        receipt,
+        user: user.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+        mplCoreProgram,
+      })
+      .signers([user])
+      .rpc({ commitment: "confirmed" });
+
+  const expectError = async (promise: Promise<unknown>, code: string) => {
+    let failed = false;
+    try {
+      await promise;
+    } catch (err) {
+      failed = true;
+      expect(err.error.errorCode.code).to.equal(code);
+    }
+    expect(failed).to.be.true;
+  };
+
+  const expectFailure = async (promise: Promise<unknown>) => {
+    let failed = false;
+    try {
+      await promise;
+    } catch (_) {
+      failed = true;
+    }
+    expect(failed).to.be.true;
+  };
+
+  const withdrawFrom = (
+    { collection, bondingCurve, vault, revenueSplit, eventInfo },
+    user = deployerKeypair,
+    recipients: PublicKey[] = [],
+  ) =>
+    program.methods
+      .withdraw()
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        config,
+        revenueSplit,
+        eventInfo,
+        collection,
+        user: user.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        recipients.map((pubkey) => ({
+          pubkey,
+          isWritable: true,
+          isSigner: false,
+        })),
+      )
+      .signers([user])
+      .rpc({ commitment: "confirmed" });
+
+  const closeCurve = ({
+    collection,
+    bondingCurve,
+    vault,
+    revenueSplit,
+    eventInfo,
+  }) =>
+    program.methods
+      .closeBondingCurve()
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        revenueSplit,
+        eventInfo,
+        collection,
+        user: deployerKeypair.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .rpc({ commitment: "confirmed" });
+
+  const setCurvePaused = (
+    { collection, bondingCurve },
+    paused: boolean,
+    user = deployerKeypair,
+  ) =>
+    program.methods
+      .setCurvePaused(paused)
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        config,
+        collection,
+        user: user.publicKey,
+      })
+      .signers([user])
+      .rpc({ commitment: "confirmed" });
+
+  const setProtocolPaused = (paused: boolean) =>
+    program.methods
+      .setPaused(paused)
+      .accounts({
+        // @ts-ignore
+        config,
+        user: deployerKeypair.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+  const fund = (pubkey: PublicKey, sol: number) =>
+    program.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: deployerKeypair.publicKey,
+          toPubkey: pubkey,
+          lamports: sol * LAMPORTS_PER_SOL,
+        }),
+      ),
+    );
+
+  const fundedKeypair = async (sol = 1) => {
+    const keypair = Keypair.generate();
+    await fund(keypair.publicKey, sol);
+    return keypair;
+  };
+
+  it("Refunds Are One-Time!", async () => {
+    // a zero window opens refunds right after the sale
+    const curve = await createCurve({ refundWindow: {} }, 5, 60);
+    await sleep(2000);
+
+    const ticket = await buyTicket(curve);
+    await sleep(5000);
+
+    await refundTicket(curve, ticket);
+    const connection = program.provider.connection;
+    expect(await connection.getAccountInfo(ticket.receipt)).to.be.null;
+
+    // the ticket is burned, Core only leaves a one-byte tombstone behind...
+    const asset = await connection.getAccountInfo(ticket.asset);
+    expect(asset.data.length).to.equal(1);
+
+    // ...so it can be neither refunded nor sold again
+    await expectFailure(refundTicket(curve, ticket));
+    await expectFailure(sellTicket(curve, ticket));
+  });
+
+  it("Slippage Bounds Are Enforced!", async () => {
+    const curve = await createCurve({ threshold: {} }, 60);
+    await sleep(2000);
+
+    // one lamport short of the fee-inclusive quote
+    const quote = await program.methods
+      .quoteBuy(new BN(1))
+      .accounts({
+        // @ts-ignore
+        bondingCurve: curve.bondingCurve,
+        collection: curve.collection,
+        config,
+      })
+      .view();
+    await expectError(
+      buyTicket(curve, { maxLamports: quote.subn(1) }),
+      "BuySlippageExceeded",
+    );
+
+    // paid-price sell-backs pay exactly the receipt's price
+    const ticket = await buyTicket(curve, { maxLamports: quote });
+    const { price } = await program.account.ticketReceipt.fetch(
+      ticket.receipt,
+    );
+    await expectError(
+      sellTicket(curve, ticket, deployerKeypair, price.addn(1)),
+      "SellSlippageExceeded",
+    );
+    await sellTicket(curve, ticket, deployerKeypair, price);
+  });
+
+  it("Last-In Curves Only Take Back The Newest Ticket!", async () => {
+    const curve = await createCurve({ threshold: {} }, 60, 0, {
+      sellPolicy: { lastIn: {} },
+    });
+    await sleep(2000);
+
+    const older = await buyTicket(curve);
+    const newer = await buyTicket(curve);
+
+    await expectError(sellTicket(curve, older), "NotLastTicket");
+    await sellTicket(curve, newer);
+    await sellTicket(curve, older);
+  });
+
+  it("Paid-Price Curves Pay Back The Ticket's Own Price!", async () => {
+    const curve = await createCurve({ threshold: {} }, 60);
+    await sleep(2000);
+
+    const older = await buyTicket(curve);
+    const newer = await buyTicket(curve);
+    const [olderReceipt, newerReceipt] = await Promise.all(
+      [older, newer].map(({ receipt }) =>
+        program.account.ticketReceipt.fetch(receipt),
+      ),
+    );
+    // the older ticket was bought lower down the curve
+    expect(olderReceipt.price.lt(newerReceipt.price)).to.be.true;
+
+    const connection = program.provider.connection;
+    const before = await connection.getBalance(curve.vault);
+    await sellTicket(curve, older);
+    const after = await connection.getBalance(curve.vault);
+    expect(before - after).to.equal(olderReceipt.price.toNumber());
+  });
+
+  it("Refund-Window Revenue Unlocks After The Refund Period!", async () => {
+    const curve = await createCurve({ refundWindow: {} }, 5, 6);
+    await sleep(2000);
+
+    // withdrawals still need the threshold of three tickets
+    const tickets = [];
+    for (let i = 0; i < 3; i++) {
+      tickets.push(await buyTicket(curve));
+    }
+    const receipts = await Promise.all(
+      tickets.map(({ receipt }) =>
+        program.account.ticketReceipt.fetch(receipt),
+      ),
+    );
+    const price = receipts.reduce(
+      (total, { price }) => total + price.toNumber(),
+      0,
+    );
+
+    // ticket prices stay reserved while refunds are open
+    await sleep(4000);
+    await expectError(withdrawFrom(curve), "NothingToClaim");
+
+    await sleep(6000);
+    await expectError(refundTicket(curve, tickets[0]), "RefundClosed");
+
+    const before = await program.provider.connection.getBalance(curve.vault);
+    await withdrawFrom(curve);
+    const after = await program.provider.connection.getBalance(curve.vault);
+    expect(before - after).to.equal(price);
+  });
+
+  it("Refund-Window Sales Below Threshold Still Settle!", async () => {
+    const curve = await createCurve({ refundWindow: {} }, 5, 3);
+    await sleep(2000);
+
+    // one ticket out of a threshold of three, never refunded
+    const ticket = await buyTicket(curve);
+    const { price } = await program.account.ticketReceipt.fetch(
+      ticket.receipt,
+    );
+    await sleep(7000);
+
+    const connection = program.provider.connection;
+    const before = await connection.getBalance(curve.vault);
+    await withdrawFrom(curve);
+    const after = await connection.getBalance(curve.vault);
+    expect(before - after).to.equal(price.toNumber());
+
+    await closeCurve(curve);
+    expect(await connection.getAccountInfo(curve.bondingCurve)).to.be.null;
+  });
+
+  it("Cancelled Events Refund The Price Paid!", async () => {
+    const curve = await createCurve({ threshold: {} }, 60);
+    await sleep(2000);
+
+    const ticket = await buyTicket(curve);
+    const { price } = await program.account.ticketReceipt.fetch(
+      ticket.receipt,
+    );
+
+    await program.methods
+      .cancelEvent()
+      .accounts({
+        // @ts-ignore
+        bondingCurve: curve.bondingCurve,
+        eventInfo: curve.eventInfo,
+        config,
+        collection: curve.collection,
+        user: deployerKeypair.publicKey,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    await expectError(buyTicket(curve), "EventCancelled");
+
+    const before = await program.provider.connection.getBalance(curve.vault);
+    await refundTicket(curve, ticket);
+    const after = await program.provider.connection.getBalance(curve.vault);
+    expect(before - after).to.equal(price.toNumber());
+  });
+
+  it("Only The Owner Can Sell Or Refund A Curve's Own Tickets!", async () => {
+    const curve = await createCurve({ threshold: {} }, 60);
+    const otherCurve = await createCurve({ threshold: {} }, 60);
+    await sleep(2000);
+
+    const ticket = await buyTicket(curve);
+    const otherTicket = await buyTicket(otherCurve);
+
+    // someone else's ticket
+    const stranger = Keypair.generate();
+    await expectError(sellTicket(curve, ticket, stranger), "InvalidAssetOwner");
+    await expectError(
+      refundTicket(curve, ticket, stranger),
+      "InvalidAssetOwner",
+    );
+
+    // a ticket minted by another curve
+    await expectError(sellTicket(curve, otherTicket), "InvalidAssetCollection");
+    await expectError(
+      refundTicket(curve, otherTicket),
+      "InvalidAssetCollection",
+    );
+  });
+
+  it("Paused Curves Halt Trading!", async () => {
+    const curve = await createCurve({ threshold: {} }, 60);
+    await sleep(2000);
+
+    const ticket = await buyTicket(curve);
+
+    // the admin's curve pause
+    await setCurvePaused(curve, true);
+    await expectError(buyTicket(curve), "CurvePaused");
+    await expectError(sellTicket(curve, ticket), "CurvePaused");
+    await setCurvePaused(curve, false);
+
+    // the protocol-wide pause
+    await setProtocolPaused(true);
+    try {
+      await expectError(buyTicket(curve), "ProtocolPaused");
+      await expectError(sellTicket(curve, ticket), "ProtocolPaused");
+    } finally {
+      await setProtocolPaused(false);
+    }
+
+    await sellTicket(curve, ticket);
+  });
+
+  it("Creators Cannot Pause Refunds!", async () => {
+    const creator = await fundedKeypair();
+    const curve = await createCurve({ refundWindow: {} }, 5, 6, { creator });
+    await sleep(2000);
+
+    const tickets = [];
+    for (let i = 0; i < 4; i++) {
+      tickets.push(await buyTicket(curve));
+    }
+
+    // the creator's pause only stops new sales
+    await setCurvePaused(curve, true, creator);
+    await expectError(buyTicket(curve), "CurvePaused");
+
+    await sleep(4000);
+    await refundTicket(curve, tickets[0]);
+
+    // the admin's pause halts refunds and withdrawals...
+    await setCurvePaused(curve, true);
+    await expectError(refundTicket(curve, tickets[1]), "CurvePaused");
+    await expectError(withdrawFrom(curve, creator), "CurvePaused");
+
+    // ...without letting the refund period run out meanwhile
+    await sleep(8000);
+    await setCurvePaused(curve, false);
+    await refundTicket(curve, tickets[1]);
+
+    // the protocol-wide pause stops withdrawals but never refunds
+    await setProtocolPaused(true);
+    try {
+      await expectError(withdrawFrom(curve, creator), "ProtocolPaused");
+      await refundTicket(curve, tickets[2]);
+    } finally {
+      await setProtocolPaused(false);
+    }
+  });
+
+  it("Undeliverable Split Shares Stay Owed!", async () => {
+    // 1% of three tickets is below an empty wallet's rent-exempt minimum
+    const recipient = Keypair.generate();
+    const curve = await createCurve({ threshold: {} }, 5, 0, {
+      revenueSplits: [{ recipient: recipient.publicKey, shareBps: 100 }],
+    });
+    await sleep(2000);
+
+    for (let i = 0; i < 3; i++) {
+      await buyTicket(curve);
+    }
+    await sleep(4000);
+
+    const connection = program.provider.connection;
+    await withdrawFrom(curve, deployerKeypair, [recipient.publicKey]);
+    expect(await connection.getBalance(recipient.publicKey)).to.equal(0);
+
+    const [owed] = (
+      await program.account.revenueSplit.fetch(curve.revenueSplit)
+    ).owed;
+    expect(owed.toNumber()).to.be.greaterThan(0);
+
+    // once the wallet exists, the recipient collects the whole share
+    await fund(recipient.publicKey, 0.01);
+    await program.methods
+      .claimSplit()
+      .accounts({
+        // @ts-ignore
+        bondingCurve: curve.bondingCurve,
+        vault: curve.vault,
+        config,
+        revenueSplit: curve.revenueSplit,
+        collection: curve.collection,
+        user: recipient.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .signers([recipient])
+      .rpc({ commitment: "confirmed" });
+
+    expect(await connection.getBalance(recipient.publicKey)).to.equal(
+      0.01 * LAMPORTS_PER_SOL + owed.toNumber(),
+    );
+    const { owed: remaining } = await program.account.revenueSplit.fetch(
+      curve.revenueSplit,
+    );
+    expect(remaining[0].toNumber()).to.equal(0);
+  });
+
+  it("Curves Cannot Close With Refunds Outstanding!", async () => {
+    const curve = await createCurve({ threshold: {} }, 5);
+    await sleep(2000);
+
+    // one ticket out of a threshold of three stays refundable after the sale
+    const ticket = await buyTicket(curve);
+    await sleep(5000);
+    await expectError(closeCurve(curve), "ObligationsOutstanding");
+
+    // the refund hands the receipt's rent back to the holder
+    await refundTicket(curve, ticket);
+    const connection = program.provider.connection;
+    expect(await connection.getAccountInfo(ticket.receipt)).to.be.null;
+
+    await closeCurve(curve);
+    expect(await connection.getAccountInfo(curve.bondingCurve)).to.be.null;
+  });
+
+  it("Curves Cannot Close While Revenue Vests!", async () => {
+    const curve = await createCurve({ threshold: {} }, 5, 0, {
+      vesting: { cliff: new BN(0), duration: new BN(3600) },
+    });
+    await sleep(2000);
+
+    for (let i = 0; i < 3; i++) {
+      await buyTicket(curve);
+    }
+    await sleep(5000);
+
+    await expectError(closeCurve(curve), "VestingInProgress");
+  });
+
+  it("Referrers Earn Part Of The Fee!", async () => {
+    const referrer = await fundedKeypair();
+    const [referral] = PublicKey.findProgramAddressSync(
+      [Buffer.from("referral"), referrer.publicKey.toBuffer()],
+      program.programId,
+    );
+
+    await program.methods
+      .initReferral()
+      .accounts({
+        // @ts-ignore
+        referral,
+        user: referrer.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .signers([referrer])
+      .rpc({ commitment: "confirmed" });
+
+    const curve = await createCurve({ threshold: {} }, 60);
+    await sleep(2000);
+
+    const ticket = await buyTicket(curve, { referral });
+    const { price } = await program.account.ticketReceipt.fetch(
+      ticket.receipt,
+    );
+    const { referralFee } = await program.account.config.fetch(config);
+    const { totalEarned } = await program.account.referral.fetch(referral);
+    expect(totalEarned.toString()).to.equal(
+      price.mul(referralFee).divn(10_000).toString(),
+    );
+
+    // referrers can't earn from their own purchases
+    await expectError(
+      buyTicket(curve, { buyer: referrer, referral }),
+      "InvalidReferrer",
+    );
+
+    const claimReferral = () =>
+      program.methods
+        .claimReferral()
+        .accounts({
+          // @ts-ignore
+          referral,
+          user: referrer.publicKey,
+        })
+        .signers([referrer])
+        .rpc({ commitment: "confirmed" });
+
+    const connection = program.provider.connection;
+    const before = await connection.getBalance(referrer.publicKey);
+    await claimReferral();
+    const after = await connection.getBalance(referrer.publicKey);
+    expect(after - before).to.equal(totalEarned.toNumber());
+
+    await expectError(claimReferral(), "NothingToClaim");
+  });
+
+  it("Event Info Locks Once The Sale Starts!", async () => {
+    const curve = await createCurve({ threshold: {} }, 60, 0, {
+      startSeconds: 4,
+    });
+    const updateEventInfo = (details) =>
+      program.methods
+        .updateEventInfo(details)
+        .accounts({
+          // @ts-ignore
+          bondingCurve: curve.bondingCurve,
+          eventInfo: curve.eventInfo,
+          collection: curve.collection,
+          user: deployerKeypair.publicKey,
+        })
+        .rpc({ commitment: "confirmed" });
+
+    const { details } = await program.account.eventInfo.fetch(curve.eventInfo);
+    const moved = { ...details, venue: "Turbin3 Annex" };
+
+    // the creator can still move the event before the sale
+    await updateEventInfo(moved);
+    const updated = await program.account.eventInfo.fetch(curve.eventInfo);
+    expect(updated.details.venue).to.equal("Turbin3 Annex");
+
+    await sleep(5000);
+    await expectError(updateEventInfo(details), "EventInfoLocked");
+  });
+
+  it("Admin Handover Takes Two Steps!", async () => {
+    const proposeAdmin = (newAdmin: PublicKey, user = deployerKeypair) =>
+      program.methods
+        .proposeAdmin(newAdmin)
+        .accounts({
+          // @ts-ignore
+          config,
+          user: user.publicKey,
+        })
+        .signers([user])
+        .rpc({ commitment: "confirmed" });
+    const acceptAdmin = (user: Keypair) =>
+      program.methods
+        .acceptAdmin()
+        .accounts({
+          // @ts-ignore
+          config,
+          user: user.publicKey,
+        })
+        .signers([user])
+        .rpc({ commitment: "confirmed" });
+
+    const newAdmin = Keypair.generate();
+    const stranger = Keypair.generate();
+
+    // only the admin can propose
+    await expectError(
+      proposeAdmin(stranger.publicKey, stranger),
+      "InvalidAuthority",
+    );
+
+    // only the proposed key can accept
+    await proposeAdmin(newAdmin.publicKey);
+    await expectError(acceptAdmin(stranger), "InvalidAuthority");
+    await acceptAdmin(newAdmin);
+
+    // the old admin is locked out once the handover is accepted
+    const { fee, feeRecipient, referralFee } =
+      await program.account.config.fetch(config);
+    await expectError(
+      program.methods
+        .updateConfig(fee, feeRecipient, referralFee)
+        .accounts({
+          // @ts-ignore
+          config,
+          user: deployerKeypair.publicKey,
+        })
+        .rpc({ commitment: "confirmed" }),
+      "InvalidAuthority",
+    );
+
+    // hand the protocol back for the remaining tests
+    await proposeAdmin(deployerKeypair.publicKey, newAdmin);
+    await acceptAdmin(deployerKeypair);
   });
 
   it("read account data", async () => {
@@ -307,6 +1336,47 @@ describe("eventdotfun-program", () => {
     const accInfo = await program.account.bondingCurve.fetch(bondingCurve);
     console.log(accInfo);
   });
+
+  it("Close Bonding Curve!", async () => {
+    const [bondingCurve] = PublicKey.findProgramAddressSync(
+      [Buffer.from("bonding_curve"), globalCollection.toBuffer()],
+      program.programId,
+    );
+
+    const [vault] = PublicKey.findProgramAddressSync(
+      [Buffer.from("vault"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const [revenueSplit] = PublicKey.findProgramAddressSync(
+      [Buffer.from("revenue_split"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const [eventInfo] = PublicKey.findProgramAddressSync(
+      [Buffer.from("event_info"), bondingCurve.toBuffer()],
+      program.programId,
+    );
+
+    const tx = await program.methods
+      .closeBondingCurve()
+      .accounts({
+        // @ts-ignore
+        bondingCurve,
+        vault,
+        revenueSplit,
+        eventInfo,
+        collection: globalCollection,
+        user: deployerKeypair.publicKey,
+        systemProgram: SYSTEM_PROGRAM_ID,
+      })
+      .rpc({ commitment: "confirmed" });
+    console.log("Your transaction signature", tx);
+
+    const connection = program.provider.connection;
+    expect(await connection.getAccountInfo(bondingCurve)).to.be.null;
+    expect(await connection.getBalance(vault)).to.equal(0);
+  });
 });
 
 const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));